@@ -11,13 +11,13 @@ where
 }
 
 fn matrix_small() -> Matrix<i32> {
-    let mut m = Matrix::new(3, 3);
+    let mut m = Matrix::zero(3, 3);
     randomize(&mut m);
     m
 }
 
 fn matrix_large() -> Matrix<i32> {
-    let mut m = Matrix::new(100, 100);
+    let mut m = Matrix::zero(100, 100);
     randomize(&mut m);
     m
 }
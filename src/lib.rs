@@ -96,9 +96,40 @@ use simple_matrix::Matrix;
 let m1: Matrix<i8> = Matrix::zero(3, 5);
 let m2: Matrix<i64> = m1.into();
 ```
+
+## ndarray
+Converts matrices to and from the [ndarray](https://github.com/rust-ndarray/ndarray) crate's *Array2*.
+
+```ignore
+use simple_matrix::Matrix;
+
+let m1: Matrix<f64> = Matrix::zero(3, 5);
+let arr = m1.to_ndarray();
+let m2 = Matrix::from_ndarray(&arr);
+```
+
+## rayon
+Computes large products in parallel with the [rayon](https://github.com/rayon-rs/rayon) crate.\
+The by-value *Mul* switches to parallel code when the product has more than
+`PARALLEL_MUL_THRESHOLD` cells, and requires the cells to be *Send* and *Sync*.\
+*Matrix::from_fn_par* builds a matrix by computing its cells in parallel.
+
+## bytemuck
+Serializes matrices of plain data to and from bytes with the [bytemuck](https://github.com/Lokathor/bytemuck) crate.\
+The format is the number of rows and columns as little-endian *u64*, followed by the cells in row-major order.
+
+```ignore
+use simple_matrix::Matrix;
+
+let m1: Matrix<f64> = Matrix::zero(3, 5);
+let bytes = m1.to_bytes();
+let m2: Matrix<f64> = Matrix::from_bytes(&bytes).unwrap();
+```
 */
 
 #![deny(missing_docs)]
+// `usize::is_multiple_of` needs Rust 1.87 and `iter::repeat_n` needs Rust 1.82
+#![allow(clippy::manual_is_multiple_of, clippy::manual_repeat_n)]
 
 mod matrix;
 
@@ -1,14 +1,41 @@
+mod axis;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod cache;
+mod convert;
+mod diagonal;
+mod elementwise;
+mod error;
+mod fmt;
 #[cfg(feature = "impl_from")]
 mod from;
 mod iter;
+mod linalg;
+mod lu;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod normalize;
+#[cfg(feature = "rayon")]
+mod rayon;
+mod reduce;
+mod shape;
 mod std_ops;
+mod transform;
+
+pub use self::axis::Axis;
+pub use self::cache::CachedMatrix;
+pub use self::error::{MatrixError, OutOfBounds};
+pub use self::lu::LuDecomposition;
+#[cfg(feature = "rayon")]
+pub use self::std_ops::PARALLEL_MUL_THRESHOLD;
+pub use self::transform::PadMode;
 
 use num_traits::{One, Zero};
 
 use std::ops::{Deref, Div, Index, IndexMut, Mul, Sub};
 
 /// A 2-Dimensional, non-resizable container.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd)]
 pub struct Matrix<T> {
     rows: usize,
     cols: usize,
@@ -16,7 +43,8 @@ pub struct Matrix<T> {
 }
 
 impl<T> Matrix<T> {
-    /// Constructs a new Matrix<T> from a 2D array.
+    /// Constructs a new Matrix<T> from a 2D array of `R` rows of `C` cells.  
+    /// See `into_array` for the reverse conversion.
     ///
     /// # Panics
     /// Panics if either `rows` or `cols` are equal to `0`
@@ -26,11 +54,12 @@ impl<T> Matrix<T> {
     /// use simple_matrix::Matrix;
     ///
     /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(mat.rows(), 3);
+    /// assert_eq!(mat.cols(), 2);
+    /// assert_eq!(mat.get(2, 0).unwrap(), 5);
     /// ```
-    pub fn new<const R: usize, const C: usize>(values: [[T; R]; C]) -> Matrix<T>
-    where
-        T: Zero,
-    {
+    pub fn new<const R: usize, const C: usize>(values: [[T; C]; R]) -> Matrix<T> {
         Matrix::from_iter(R, C, values.into_iter().flatten())
     }
 
@@ -113,6 +142,102 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Constructs a new, non-empty Matrix<T> where every cell is computed from its coordinates.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat = Matrix::from_fn(2, 3, |row, col| row * 10 + col);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, vec![0, 1, 2, 10, 11, 12]));
+    /// ```
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Matrix<T> {
+        Matrix::from_iter(rows, cols, (0..rows * cols).map(|i| f(i / cols, i % cols)))
+    }
+
+    /// Constructs a new *times*x*row.len()* Matrix<T> where every row is a copy of `row`.
+    ///
+    /// # Panics
+    /// Panics if `times` is equal to `0` or `row` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat = Matrix::from_repeated_row(&[1, 2, 3], 3);
+    ///
+    /// assert_eq!(mat, Matrix::new([[1, 2, 3], [1, 2, 3], [1, 2, 3]]));
+    /// ```
+    pub fn from_repeated_row(row: &[T], times: usize) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_iter(times, row.len(), row.iter().cloned().cycle())
+    }
+
+    /// Constructs a new *col.len()*x*times* Matrix<T> where every column is a copy of `col`.
+    ///
+    /// # Panics
+    /// Panics if `times` is equal to `0` or `col` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat = Matrix::from_repeated_col(&[1, 2], 3);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, vec![1, 1, 1, 2, 2, 2]));
+    /// ```
+    pub fn from_repeated_col(col: &[T], times: usize) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_iter(
+            col.len(),
+            times,
+            col.iter()
+                .flat_map(|value| std::iter::repeat(value.clone()).take(times)),
+        )
+    }
+
+    /// Try to construct the one-hot encoding of class labels, a *labels.len()*x*num_classes*
+    /// Matrix<T> where row `i` has a one in column `labels[i]` and zeros elsewhere.  
+    /// Returns `None` if `labels` is empty, `num_classes` is `0`,
+    /// or any label is not smaller than `num_classes`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f32> = Matrix::one_hot(&[0, 2, 1], 3).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new([
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 1.0],
+    ///     [0.0, 1.0, 0.0],
+    /// ]));
+    /// assert!(Matrix::<f32>::one_hot(&[0, 3], 3).is_none());
+    /// assert!(Matrix::<f32>::one_hot(&[], 3).is_none());
+    /// ```
+    pub fn one_hot(labels: &[usize], num_classes: usize) -> Option<Matrix<T>>
+    where
+        T: Zero + One + Clone,
+    {
+        if labels.is_empty() || labels.iter().any(|&label| label >= num_classes) {
+            return None;
+        }
+
+        let mut result = Self::zero(labels.len(), num_classes);
+        for (row, &label) in labels.iter().enumerate() {
+            result.data[label + row * num_classes] = T::one();
+        }
+        Some(result)
+    }
+
     /// Returns the number of rows in the matrix.
     ///
     /// # Examples
@@ -234,6 +359,138 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Get the value at given row & column.  
+    /// Returns an `OutOfBounds` error carrying the requested cell and the matrix shape
+    /// if `row` or `col` is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, OutOfBounds};
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    ///
+    /// assert_eq!(mat.try_get(2, 5), Ok(17));
+    /// assert_eq!(
+    ///     mat.try_get(1, 6),
+    ///     Err(OutOfBounds { row: 1, col: 6, rows: 3, cols: 6 })
+    /// );
+    /// ```
+    pub fn try_get(&self, row: usize, col: usize) -> Result<T, OutOfBounds>
+    where
+        T: Clone,
+    {
+        self.try_get_ref(row, col).cloned()
+    }
+
+    /// Get a reference to the value at given row & column.  
+    /// Returns an `OutOfBounds` error if `row` or `col` is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    ///
+    /// assert_eq!(mat.try_get_ref(0, 1), Ok(&1));
+    /// assert_eq!(mat.try_get_ref(3, 0).unwrap_err().row, 3);
+    /// ```
+    pub fn try_get_ref(&self, row: usize, col: usize) -> Result<&T, OutOfBounds> {
+        let err = self.out_of_bounds(row, col);
+        self.get_ref(row, col).ok_or(err)
+    }
+
+    /// Set the cell at given row & column to the given value.  
+    /// Returns an `OutOfBounds` error if `row` or `col` is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    ///
+    /// assert!(mat.try_set(0, 0, 5).is_ok());
+    /// assert_eq!(mat.get(0, 0).unwrap(), 5);
+    ///
+    /// let err = mat.try_set(0, 10, 5).unwrap_err();
+    /// assert_eq!((err.row, err.col), (0, 10));
+    /// ```
+    pub fn try_set(&mut self, row: usize, col: usize, value: T) -> Result<(), OutOfBounds> {
+        let err = self.out_of_bounds(row, col);
+        *self.get_mut(row, col).ok_or(err)? = value;
+        Ok(())
+    }
+
+    /// Try to get a copy of the cells at the given `(row, col)` coordinates, in order.  
+    /// Returns `None` if any coordinate is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 4, 0..);
+    ///
+    /// assert_eq!(mat.get_many(&[(2, 3), (0, 1), (2, 3)]), Some(vec![11, 1, 11]));
+    /// assert!(mat.get_many(&[(0, 0), (3, 0)]).is_none());
+    /// ```
+    pub fn get_many(&self, coords: &[(usize, usize)]) -> Option<Vec<T>>
+    where
+        T: Clone,
+    {
+        coords
+            .iter()
+            .map(|&(row, col)| self.get(row, col))
+            .collect()
+    }
+
+    /// Try to set the cells at the given `(row, col)` coordinates, in order.  
+    /// Returns `false` (and leaves the matrix untouched) if any coordinate is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::zero(2, 2);
+    ///
+    /// assert!(mat.set_many(&[((0, 1), 5), ((1, 0), 7)]));
+    /// assert_eq!(mat, Matrix::new([[0, 5], [7, 0]]));
+    ///
+    /// assert!(!mat.set_many(&[((0, 0), 1), ((0, 2), 1)]));
+    /// assert_eq!(mat.get(0, 0).unwrap(), 0);
+    /// ```
+    pub fn set_many(&mut self, updates: &[((usize, usize), T)]) -> bool
+    where
+        T: Clone,
+    {
+        if updates
+            .iter()
+            .any(|&((row, col), _)| row >= self.rows || col >= self.cols)
+        {
+            return false;
+        }
+
+        for ((row, col), value) in updates {
+            self.data[col + row * self.cols] = value.clone();
+        }
+        true
+    }
+
+    // Index of a cell in `data`, used by the `Index` implementations
+    fn index_of(&self, row: usize, col: usize) -> usize {
+        if row >= self.rows || col >= self.cols {
+            panic!("{}", self.out_of_bounds(row, col));
+        }
+        col + row * self.cols
+    }
+
+    fn out_of_bounds(&self, row: usize, col: usize) -> OutOfBounds {
+        OutOfBounds {
+            row,
+            col,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
     /// Try to get an iterator of all cells of the requested row.  
     /// Returns `None` if given row is outside of the matrix.
     ///
@@ -276,6 +533,121 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Try to get an iterator of all cells of the requested row, like `get_row`.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::IndexOutOfRange` if given row is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Axis, Matrix, MatrixError};
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    ///
+    /// assert_eq!(mat.try_row(1).unwrap().cloned().collect::<Vec<usize>>(), vec![6, 7, 8, 9, 10, 11]);
+    ///
+    /// assert_eq!(
+    ///     mat.try_row(5).err(),
+    ///     Some(MatrixError::IndexOutOfRange { axis: Axis::Rows, index: 5, len: 3 })
+    /// );
+    /// ```
+    pub fn try_row(&self, row: usize) -> Result<impl Iterator<Item = &T>, MatrixError> {
+        self.get_row(row).ok_or(MatrixError::IndexOutOfRange {
+            axis: Axis::Rows,
+            index: row,
+            len: self.rows,
+        })
+    }
+
+    /// Try to get an iterator of all cells of the requested column, like `get_col`.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::IndexOutOfRange` if given column is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Axis, Matrix, MatrixError};
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    ///
+    /// assert_eq!(mat.try_col(1).unwrap().cloned().collect::<Vec<usize>>(), vec![1, 7, 13]);
+    ///
+    /// assert_eq!(
+    ///     mat.try_col(10).err(),
+    ///     Some(MatrixError::IndexOutOfRange { axis: Axis::Cols, index: 10, len: 6 })
+    /// );
+    /// ```
+    pub fn try_col(&self, col: usize) -> Result<impl Iterator<Item = &T>, MatrixError> {
+        self.get_col(col).ok_or(MatrixError::IndexOutOfRange {
+            axis: Axis::Cols,
+            index: col,
+            len: self.cols,
+        })
+    }
+
+    /// Try to replace all cells of the requested row.  
+    /// Returns `false` if given row is outside of the matrix or `values` does not have `cols` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert!(mat.set_row(0, &[9, 9, 9]));
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, vec![9, 9, 9, 3, 4, 5]));
+    ///
+    /// assert!(!mat.set_row(0, &[9, 9]));
+    /// assert!(!mat.set_row(2, &[9, 9, 9]));
+    /// ```
+    pub fn set_row(&mut self, row: usize, values: &[T]) -> bool
+    where
+        T: Clone,
+    {
+        if row >= self.rows || values.len() != self.cols {
+            return false;
+        }
+
+        let start = row * self.cols;
+        self.data[start..start + self.cols].clone_from_slice(values);
+        true
+    }
+
+    /// Try to replace all cells of the requested column.  
+    /// Returns `false` if given column is outside of the matrix or `values` does not have `rows` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert!(mat.set_col(2, &[7, 8]));
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, vec![0, 1, 7, 3, 4, 8]));
+    ///
+    /// assert!(!mat.set_col(1, &[7, 8, 9]));
+    /// assert!(!mat.set_col(3, &[7, 8]));
+    /// ```
+    pub fn set_col(&mut self, col: usize, values: &[T]) -> bool
+    where
+        T: Clone,
+    {
+        if col >= self.cols || values.len() != self.rows {
+            return false;
+        }
+
+        for (cell, value) in self
+            .data
+            .iter_mut()
+            .skip(col)
+            .step_by(self.cols)
+            .zip(values)
+        {
+            *cell = value.clone();
+        }
+        true
+    }
+
     /// Swaps row at the specified indices.
     pub fn swap_rows(&mut self, row1: usize, row2: usize) {
         for col in 0..self.cols {
@@ -284,6 +656,59 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Reorder the rows in place, so that row `i` becomes the row previously at `perm[i]`.  
+    /// The permutation is applied by following its cycles with row swaps,
+    /// without allocating a new matrix.  
+    /// Returns `false` (and leaves the matrix untouched) if `perm` is not a permutation of `0..rows`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<usize> = Matrix::from_iter(4, 2, 0..);
+    /// let original = mat.clone();
+    ///
+    /// assert!(mat.apply_row_permutation_inplace(&[2, 0, 3, 1]));
+    /// assert_eq!(mat.get_row(0).unwrap().cloned().collect::<Vec<_>>(), vec![4, 5]);
+    /// assert_eq!(mat.get_row(3).unwrap().cloned().collect::<Vec<_>>(), vec![2, 3]);
+    ///
+    /// // Apply the inverse permutation
+    /// assert!(mat.apply_row_permutation_inplace(&[1, 3, 0, 2]));
+    /// assert_eq!(mat, original);
+    ///
+    /// assert!(!mat.apply_row_permutation_inplace(&[0, 0, 1, 2]));
+    /// assert!(!mat.apply_row_permutation_inplace(&[0, 1]));
+    /// ```
+    pub fn apply_row_permutation_inplace(&mut self, perm: &[usize]) -> bool {
+        if perm.len() != self.rows {
+            return false;
+        }
+
+        let mut visited = vec![false; self.rows];
+        for &row in perm {
+            if row >= self.rows || visited[row] {
+                return false;
+            }
+            visited[row] = true;
+        }
+
+        visited.iter_mut().for_each(|v| *v = false);
+        for start in 0..self.rows {
+            if visited[start] {
+                continue;
+            }
+
+            let mut current = start;
+            visited[current] = true;
+            while perm[current] != start {
+                self.swap_rows(current, perm[current]);
+                current = perm[current];
+                visited[current] = true;
+            }
+        }
+        true
+    }
+
     /// Swaps columns at the specified indices.
     pub fn swap_cols(&mut self, col1: usize, col2: usize) {
         for row in 0..self.rows {
@@ -292,7 +717,9 @@ impl<T> Matrix<T> {
         }
     }
 
-    /// Take a *M*x*N* Matrix and construct the transposed *N*x*M* Matrix.
+    /// Take a *M*x*N* Matrix and construct the transposed *N*x*M* Matrix.  
+    /// Row and column vectors have the same cells in the same order once transposed,
+    /// so their cells are copied as a whole instead of column by column.
     ///
     /// # Examples
     /// ```
@@ -306,11 +733,24 @@ impl<T> Matrix<T> {
     ///
     /// assert_eq!(mat.get(0, 0).unwrap(), mat_t.get(0, 0).unwrap());
     /// assert_eq!(mat.get(1, 2).unwrap(), mat_t.get(2, 1).unwrap());
+    ///
+    /// let row: Matrix<usize> = Matrix::from_iter(1, 5, 0..);
+    /// let col = row.transpose();
+    /// assert_eq!((col.rows(), col.cols()), (5, 1));
+    /// assert_eq!(col.to_vec(), row.to_vec());
     /// ```
     pub fn transpose(&self) -> Matrix<T>
     where
         T: Clone,
     {
+        if self.rows == 1 || self.cols == 1 {
+            return Matrix {
+                rows: self.cols,
+                cols: self.rows,
+                data: self.data.clone(),
+            };
+        }
+
         Matrix {
             rows: self.cols,
             cols: self.rows,
@@ -326,7 +766,70 @@ impl<T> Matrix<T> {
         }
     }
 
-    /// Take a *N*x*N* Matrix and construct the inverse of it.
+    /// Transpose a *M*x*N* Matrix into a *N*x*M* Matrix, reusing its buffer.  
+    /// Row and column vectors only swap their dimensions, other matrices move their cells
+    /// in place along the cycles of the transposition, so the cells are never cloned.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 4, 0..);
+    /// assert_eq!(mat.clone().into_transpose(), mat.transpose());
+    ///
+    /// let row: Matrix<usize> = Matrix::from_iter(1, 5, 0..);
+    /// let buffer = row.as_ptr();
+    /// let col = row.into_transpose();
+    /// assert_eq!((col.rows(), col.cols()), (5, 1));
+    /// assert_eq!(col.as_ptr(), buffer);
+    /// ```
+    pub fn into_transpose(mut self) -> Matrix<T> {
+        if self.rows != 1 && self.cols != 1 {
+            // Cell `i` of the result is cell `(i % rows, i / rows)` of the original
+            let source = |i: usize| (i % self.rows) * self.cols + i / self.rows;
+            let mut visited = vec![false; self.data.len()];
+            for start in 0..self.data.len() {
+                let mut current = start;
+                while !visited[current] {
+                    visited[current] = true;
+                    let next = source(current);
+                    if next == start {
+                        break;
+                    }
+                    self.data.swap(current, next);
+                    current = next;
+                }
+            }
+        }
+
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: self.data,
+        }
+    }
+
+    /// Construct the transposed matrix, an alias of `transpose`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.transposed(), mat.transpose());
+    /// ```
+    pub fn transposed(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        self.transpose()
+    }
+
+    /// Take a *N*x*N* Matrix and construct the inverse of it.  
+    /// Returns `None` if the matrix is not square or is singular.  
+    /// Integer cells (where `1 / 2 == 0`) are eliminated without fractions,
+    /// and `None` is returned instead of a truncated result if the inverse is not integral.
     ///
     /// # Examples
     /// ```
@@ -348,74 +851,147 @@ impl<T> Matrix<T> {
     /// ])) {
     ///     assert!((value - expected).abs() < 0.01);
     /// }
+    ///
+    /// let singular: Matrix<f32> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!(singular.inverse().is_none());
+    ///
+    /// // Integer matrices are only inverted when no fractions are needed
+    /// let unimodular: Matrix<i32> = Matrix::new([[1, 2], [0, 1]]);
+    /// assert_eq!(unimodular.inverse(), Some(Matrix::new([[1, -2], [0, 1]])));
+    ///
+    /// let unimodular: Matrix<i32> = Matrix::new([[2, 1], [1, 1]]);
+    /// assert_eq!(unimodular.inverse(), Some(Matrix::new([[1, -1], [-1, 2]])));
+    ///
+    /// let fractional: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    /// assert!(fractional.inverse().is_none());
     /// ```
     pub fn inverse(&self) -> Option<Matrix<T>>
     where
         T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
     {
-        if self.rows != self.cols {
-            return None;
+        let mut result = Matrix::zero(self.rows, self.cols);
+        if self.inverse_into(&mut result) {
+            Some(result)
+        } else {
+            None
         }
+    }
 
-        let len = self.rows;
-        let mut matrix: Matrix<T> = Matrix::zero(len, len * 2);
-        for i in 0..len {
-            for j in 0..len {
-                matrix.set(i, j, self.get(i, j).unwrap());
-            }
-            matrix.set(i, i + len, T::one());
+    /// Take a *N*x*N* Matrix and write its inverse into `dst`, reusing its allocation.  
+    /// Returns `false` if the matrix is not square, if `dst` is not *N*x*N*,
+    /// or if the matrix cannot be inverted (see `inverse`).
+    /// The content of `dst` is unspecified when `false` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+    /// let mut dst: Matrix<f64> = Matrix::zero(2, 2);
+    ///
+    /// assert!(mat.inverse_into(&mut dst));
+    /// assert_eq!(dst, mat.inverse().unwrap());
+    ///
+    /// let singular: Matrix<f64> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!(!singular.inverse_into(&mut dst));
+    ///
+    /// assert!(!mat.inverse_into(&mut Matrix::zero(3, 3)));
+    /// ```
+    pub fn inverse_into(&self, dst: &mut Matrix<T>) -> bool
+    where
+        T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        if self.rows != self.cols || (dst.rows, dst.cols) != (self.rows, self.cols) {
+            return false;
         }
 
-        let mut lead = 0;
+        let len = self.rows;
+        for (i, value) in dst.data.iter_mut().enumerate() {
+            *value = if i % (len + 1) == 0 {
+                T::one()
+            } else {
+                T::zero()
+            };
+        }
 
-        for r in 0..matrix.rows {
-            if matrix.cols <= lead {
-                break;
-            }
-            let mut i = r;
-            while matrix.get_ref(i, lead).unwrap().is_zero() {
-                i = i + 1;
-                if matrix.rows == i {
-                    i = r;
-                    lead = lead + 1;
-                    if matrix.cols == lead {
-                        break;
-                    }
-                }
-            }
+        // Integer division truncates, so integers are eliminated without fractions
+        if (T::one() / (T::one() + T::one())).is_zero() {
+            return self.inverse_fraction_free_into(dst);
+        }
 
-            matrix.swap_rows(i, r);
+        let mut matrix = self.clone();
+        for r in 0..len {
+            let pivot = match (r..len).find(|&i| !matrix.data[r + i * len].is_zero()) {
+                Some(pivot) => pivot,
+                // No pivot left in this column: the matrix is singular
+                None => return false,
+            };
+            matrix.swap_rows(pivot, r);
+            dst.swap_rows(pivot, r);
 
-            let div = matrix.get_ref(r, lead).unwrap();
-            if !div.is_zero() {
-                let div = div.clone();
-                for j in 0..matrix.cols {
-                    let value = matrix.get_mut(r, j).unwrap();
+            let div = matrix.data[r + r * len].clone();
+            for mat in [&mut matrix, &mut *dst] {
+                for value in &mut mat.data[r * len..(r + 1) * len] {
                     *value = value.clone() / div.clone();
                 }
             }
 
-            for k in 0..matrix.rows {
-                if k != r {
-                    let mul = matrix.get(k, lead).unwrap();
-                    for j in 0..matrix.cols {
-                        let subtracted = matrix.get(r, j).unwrap() * mul.clone();
-                        let value = matrix.get_mut(k, j).unwrap();
+            for k in (0..len).filter(|&k| k != r) {
+                let mul = matrix.data[r + k * len].clone();
+                for mat in [&mut matrix, &mut *dst] {
+                    for j in 0..len {
+                        let subtracted = mat.data[j + r * len].clone() * mul.clone();
+                        let value = &mut mat.data[j + k * len];
                         *value = value.clone() - subtracted;
                     }
                 }
             }
+        }
 
-            lead += 1;
+        true
+    }
+
+    // Fraction-free (Bareiss) Gauss-Jordan elimination of `self` next to the identity in `dst`.
+    // Every division by the previous pivot is exact, the left block ends up as `d * I`
+    // and `dst` as `d * A^-1`, with `d = ±det(A)`, so only the final division can be inexact.
+    fn inverse_fraction_free_into(&self, dst: &mut Matrix<T>) -> bool
+    where
+        T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        let len = self.rows;
+        let mut matrix = self.clone();
+        let mut previous = T::one();
+        for r in 0..len {
+            let pivot = match (r..len).find(|&i| !matrix.data[r + i * len].is_zero()) {
+                Some(pivot) => pivot,
+                None => return false,
+            };
+            matrix.swap_rows(pivot, r);
+            dst.swap_rows(pivot, r);
+
+            let div = matrix.data[r + r * len].clone();
+            for k in (0..len).filter(|&k| k != r) {
+                let mul = matrix.data[r + k * len].clone();
+                for mat in [&mut matrix, &mut *dst] {
+                    for j in 0..len {
+                        let value = mat.data[j + k * len].clone() * div.clone()
+                            - mul.clone() * mat.data[j + r * len].clone();
+                        mat.data[j + k * len] = value / previous.clone();
+                    }
+                }
+            }
+            previous = div;
         }
 
-        let mut result: Matrix<T> = Matrix::zero(len, len);
-        for i in 0..len {
-            for j in 0..len {
-                result.set(i, j, matrix.get(i, j + len).unwrap());
+        for value in dst.data.iter_mut() {
+            let quotient = value.clone() / previous.clone();
+            if !(quotient.clone() * previous.clone() - value.clone()).is_zero() {
+                return false;
             }
+            *value = quotient;
         }
-        Some(result)
+
+        true
     }
 
     /// Apply a function to all cells of the matrix.  
@@ -433,8 +1009,8 @@ impl<T> Matrix<T> {
     ///
     /// assert_eq!(sum, 153);
     /// ```
-    pub fn apply<F: FnMut(&T)>(&self, mut func: F) {
-        self.data.iter().for_each(|n| func(n));
+    pub fn apply<F: FnMut(&T)>(&self, func: F) {
+        self.data.iter().for_each(func);
     }
 
     /// Apply a function to all cells of the matrix.  
@@ -453,8 +1029,93 @@ impl<T> Matrix<T> {
     /// assert_eq!(mat.get(0, 1).unwrap(), 2);
     /// assert_eq!(mat.get(0, 2).unwrap(), 4);
     /// ```
-    pub fn apply_mut<F: FnMut(&mut T)>(&mut self, mut func: F) {
-        self.data.iter_mut().for_each(|n| func(n));
+    pub fn apply_mut<F: FnMut(&mut T)>(&mut self, func: F) {
+        self.data.iter_mut().for_each(func);
+    }
+
+    /// Try to apply a function to all cells of the requested row.  
+    /// Returns `false` if given row is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<usize> = Matrix::from_iter(3, 2, 0..);
+    ///
+    /// assert!(mat.apply_to_row(1, |n| *n *= 2));
+    /// assert_eq!(mat, Matrix::from_iter(3, 2, vec![0, 1, 4, 6, 4, 5]));
+    ///
+    /// assert!(!mat.apply_to_row(3, |n| *n *= 2));
+    /// ```
+    pub fn apply_to_row<F: FnMut(&mut T)>(&mut self, row: usize, func: F) -> bool {
+        if row >= self.rows {
+            return false;
+        }
+
+        let start = row * self.cols;
+        self.data[start..start + self.cols]
+            .iter_mut()
+            .for_each(func);
+        true
+    }
+
+    /// Try to apply a function to all cells of the requested column.  
+    /// Returns `false` if given column is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<usize> = Matrix::from_iter(3, 2, 0..);
+    ///
+    /// assert!(mat.apply_to_col(0, |n| *n += 10));
+    /// assert_eq!(mat, Matrix::from_iter(3, 2, vec![10, 1, 12, 3, 14, 5]));
+    ///
+    /// assert!(!mat.apply_to_col(2, |n| *n += 10));
+    /// ```
+    pub fn apply_to_col<F: FnMut(&mut T)>(&mut self, col: usize, func: F) -> bool {
+        if col >= self.cols {
+            return false;
+        }
+
+        self.data
+            .iter_mut()
+            .skip(col)
+            .step_by(self.cols)
+            .for_each(func);
+        true
+    }
+}
+
+/// `clone_from` reuses the allocation of the target matrix when it is large enough,
+/// which avoids reallocating when repeatedly copying matrices of the same shape.
+///
+/// # Examples
+/// ```
+/// use simple_matrix::Matrix;
+///
+/// let source: Matrix<usize> = Matrix::from_iter(3, 3, 0..);
+/// let mut target: Matrix<usize> = Matrix::zero(3, 3);
+/// let buffer = target.as_ptr();
+///
+/// target.clone_from(&source);
+///
+/// assert_eq!(target, source);
+/// assert_eq!(target.as_ptr(), buffer);
+/// ```
+impl<T: Clone> Clone for Matrix<T> {
+    fn clone(&self) -> Self {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.rows = source.rows;
+        self.cols = source.cols;
+        self.data.clone_from(&source.data);
     }
 }
 
@@ -470,7 +1131,7 @@ impl<T> Index<[usize; 2]> for Matrix<T> {
     type Output = T;
 
     fn index(&self, [row, col]: [usize; 2]) -> &Self::Output {
-        &self.data[col + row * self.cols]
+        &self.data[self.index_of(row, col)]
     }
 }
 
@@ -478,18 +1139,20 @@ impl<T> Index<(usize, usize)> for Matrix<T> {
     type Output = T;
 
     fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
-        &self.data[col + row * self.cols]
+        &self.data[self.index_of(row, col)]
     }
 }
 
 impl<T> IndexMut<[usize; 2]> for Matrix<T> {
     fn index_mut(&mut self, [row, col]: [usize; 2]) -> &mut Self::Output {
-        &mut self.data[col + row * self.cols]
+        let index = self.index_of(row, col);
+        &mut self.data[index]
     }
 }
 
 impl<T> IndexMut<(usize, usize)> for Matrix<T> {
     fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
-        &mut self.data[col + row * self.cols]
+        let index = self.index_of(row, col);
+        &mut self.data[index]
     }
 }
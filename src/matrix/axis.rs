@@ -0,0 +1,8 @@
+/// One of the two axes of a matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The rows of the matrix, indexed by the first coordinate.
+    Rows,
+    /// The columns of the matrix, indexed by the second coordinate.
+    Cols,
+}
@@ -0,0 +1,117 @@
+use super::{Matrix, MatrixError};
+use ::bytemuck::Pod;
+use std::mem::size_of;
+
+const HEADER_LEN: usize = 2 * size_of::<u64>();
+
+impl<T: Pod> Matrix<T> {
+    /// Serialize the matrix to bytes: the number of rows and columns as little-endian `u64`,
+    /// followed by the cells in row-major order.  
+    /// Cells are written in little-endian order on every target, by reversing the bytes
+    /// of each cell on big-endian targets, which is correct for primitive cell types.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<u16> = Matrix::from_iter(1, 2, vec![1, 0x0203]);
+    ///
+    /// assert_eq!(mat.to_bytes(), vec![
+    ///     1, 0, 0, 0, 0, 0, 0, 0,
+    ///     2, 0, 0, 0, 0, 0, 0, 0,
+    ///     1, 0, 3, 2,
+    /// ]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let cells: &[u8] = ::bytemuck::cast_slice(&self.data);
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + cells.len());
+        bytes.extend_from_slice(&(self.rows as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.cols as u64).to_le_bytes());
+        bytes.extend_from_slice(cells);
+
+        if cfg!(target_endian = "big") && size_of::<T>() > 0 {
+            bytes[HEADER_LEN..]
+                .chunks_exact_mut(size_of::<T>())
+                .for_each(|cell| cell.reverse());
+        }
+
+        bytes
+    }
+
+    /// Deserialize a matrix written by `to_bytes`.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::InvalidBytes` if `bytes` is shorter than the header,
+    /// or does not have exactly the length announced by the header.  
+    /// Returns `MatrixError::EmptyDimension` if the header announces `0` rows or columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, MatrixError};
+    ///
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 3, (0..).map(|n| f64::from(n) / 3.0));
+    /// let bytes = mat.to_bytes();
+    ///
+    /// assert_eq!(Matrix::from_bytes(&bytes), Ok(mat));
+    ///
+    /// assert_eq!(
+    ///     Matrix::<f64>::from_bytes(&bytes[..bytes.len() - 1]),
+    ///     Err(MatrixError::InvalidBytes { expected: 64, found: 63 })
+    /// );
+    /// assert_eq!(
+    ///     Matrix::<f64>::from_bytes(&bytes[..4]),
+    ///     Err(MatrixError::InvalidBytes { expected: 16, found: 4 })
+    /// );
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Matrix<T>, MatrixError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(MatrixError::InvalidBytes {
+                expected: HEADER_LEN,
+                found: bytes.len(),
+            });
+        }
+
+        let (header, cells) = bytes.split_at(HEADER_LEN);
+        let (rows, cols) = header.split_at(size_of::<u64>());
+        let dimension = |bytes: &[u8]| {
+            let value = u64::from_le_bytes(bytes.try_into().unwrap());
+            usize::try_from(value).unwrap_or(usize::MAX)
+        };
+        let (rows, cols) = (dimension(rows), dimension(cols));
+
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::EmptyDimension { rows, cols });
+        }
+
+        let expected = rows
+            .saturating_mul(cols)
+            .saturating_mul(size_of::<T>())
+            .saturating_add(HEADER_LEN);
+        if bytes.len() != expected {
+            return Err(MatrixError::InvalidBytes {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        let data = if size_of::<T>() == 0 {
+            vec![T::zeroed(); rows * cols]
+        } else {
+            cells
+                .chunks_exact(size_of::<T>())
+                .map(|cell| {
+                    if cfg!(target_endian = "big") {
+                        let mut cell = cell.to_vec();
+                        cell.reverse();
+                        ::bytemuck::pod_read_unaligned(&cell)
+                    } else {
+                        ::bytemuck::pod_read_unaligned(cell)
+                    }
+                })
+                .collect()
+        };
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
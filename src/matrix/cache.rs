@@ -0,0 +1,80 @@
+use super::Matrix;
+use num_traits::{One, Zero};
+use std::cell::OnceCell;
+use std::ops::{Div, Mul, Neg, Sub};
+
+/// An immutable matrix which computes its determinant and inverse at most once.
+///
+/// Both values are computed lazily, on the first call to `det` or `inv`,
+/// and the following calls reuse them.
+///
+/// # Examples
+/// ```
+/// use simple_matrix::{CachedMatrix, Matrix};
+///
+/// let cached: CachedMatrix<f64> = CachedMatrix::new(Matrix::new([[4.0, 7.0], [2.0, 6.0]]));
+///
+/// let det = cached.det().unwrap();
+/// assert!((det - 10.0).abs() < 1e-12);
+///
+/// // The second call returns the stored value instead of computing it again
+/// assert!(std::ptr::eq(det, cached.det().unwrap()));
+/// assert!(std::ptr::eq(cached.inv().unwrap(), cached.inv().unwrap()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CachedMatrix<T> {
+    matrix: Matrix<T>,
+    det: OnceCell<Option<T>>,
+    inv: OnceCell<Option<Matrix<T>>>,
+}
+
+impl<T> CachedMatrix<T> {
+    /// Wrap a matrix, nothing is computed yet.
+    pub fn new(matrix: Matrix<T>) -> CachedMatrix<T> {
+        CachedMatrix {
+            matrix,
+            det: OnceCell::new(),
+            inv: OnceCell::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped matrix.
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Unwrap the matrix, dropping the cached values.
+    pub fn into_inner(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Returns the determinant of the matrix, computing it on the first call.  
+    /// Returns `None` if the matrix is not square.
+    pub fn det(&self) -> Option<&T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        self.det.get_or_init(|| self.matrix.determinant()).as_ref()
+    }
+
+    /// Returns the inverse of the matrix, computing it on the first call.  
+    /// Returns `None` if the matrix is not square or is singular.
+    pub fn inv(&self) -> Option<&Matrix<T>>
+    where
+        T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.inv.get_or_init(|| self.matrix.inverse()).as_ref()
+    }
+}
+
+impl<T> From<Matrix<T>> for CachedMatrix<T> {
+    fn from(matrix: Matrix<T>) -> Self {
+        CachedMatrix::new(matrix)
+    }
+}
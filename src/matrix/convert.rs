@@ -0,0 +1,118 @@
+use super::{Matrix, MatrixError};
+use num_traits::Zero;
+
+/// Constructs a Matrix<T> from row-major `data` and its `(rows, cols)` shape.
+///
+/// # Errors
+/// Returns `MatrixError::EmptyDimension` if either `rows` or `cols` are equal to `0`.
+/// Returns `MatrixError::LengthMismatch` if `data` does not have `rows * cols` values.
+///
+/// # Examples
+/// ```
+/// use simple_matrix::{Matrix, MatrixError};
+///
+/// let mat = Matrix::try_from((vec![1, 2, 3, 4, 5, 6], 2, 3)).unwrap();
+/// assert_eq!(mat.rows(), 2);
+/// assert_eq!(mat.get(1, 0).unwrap(), 4);
+///
+/// assert_eq!(
+///     Matrix::try_from((Vec::<i32>::new(), 0, 3)),
+///     Err(MatrixError::EmptyDimension { rows: 0, cols: 3 })
+/// );
+/// assert_eq!(
+///     Matrix::try_from((vec![1, 2, 3], 2, 2)),
+///     Err(MatrixError::LengthMismatch { expected: 4, found: 3 })
+/// );
+/// ```
+impl<T> TryFrom<(Vec<T>, usize, usize)> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from((data, rows, cols): (Vec<T>, usize, usize)) -> Result<Self, Self::Error> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::EmptyDimension { rows, cols });
+        }
+        if data.len() != rows * cols {
+            return Err(MatrixError::LengthMismatch {
+                expected: rows * cols,
+                found: data.len(),
+            });
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Consume the matrix and return its cells in row-major order, without cloning them.  
+    /// See `to_vec` for a copy of the cells.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    /// let data = mat.into_vec();
+    ///
+    /// assert_eq!(data.len(), 6);
+    /// assert_eq!(data, vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Try to move the cells into a 2D array of `R` rows of `C` cells, the reverse of `new`.  
+    /// Returns the matrix back as the error if it is not a *R*x*C* matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat = Matrix::new([[1, 2], [3, 4]]);
+    /// assert_eq!(mat.into_array(), Ok([[1, 2], [3, 4]]));
+    ///
+    /// let wide: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    /// let wide = wide.into_array::<3, 2>().unwrap_err();
+    /// assert_eq!(wide.into_array::<2, 3>(), Ok([[0, 1, 2], [3, 4, 5]]));
+    /// ```
+    pub fn into_array<const R: usize, const C: usize>(self) -> Result<[[T; C]; R], Matrix<T>> {
+        if self.rows != R || self.cols != C {
+            return Err(self);
+        }
+
+        let mut cells = self.data.into_iter();
+        Ok(std::array::from_fn(|_| {
+            std::array::from_fn(|_| cells.next().unwrap())
+        }))
+    }
+
+    /// Returns the non-zero cells as `(row, col, value)` triplets, the coordinate (COO)
+    /// format of sparse matrices.  
+    /// The triplets are sorted by `(row, col)`, as the cells are stored row by row,
+    /// so they can be fed to a CSR builder without sorting them again.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([
+    ///     [0, 5, 0],
+    ///     [0, 0, 0],
+    ///     [7, 0, 9],
+    /// ]);
+    /// let triplets = mat.to_coo_sorted();
+    ///
+    /// assert_eq!(triplets, vec![(0, 1, 5), (2, 0, 7), (2, 2, 9)]);
+    /// assert!(triplets.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    /// ```
+    pub fn to_coo_sorted(&self) -> Vec<(usize, usize, T)>
+    where
+        T: Clone + Zero + PartialEq,
+    {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !value.is_zero())
+            .map(|(i, value)| (i / self.cols, i % self.cols, value.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,319 @@
+use super::Matrix;
+use num_traits::Zero;
+use std::ops::{Add, AddAssign, Mul};
+
+impl<T> Matrix<T> {
+    /// Returns the cells of the main diagonal, there are `min(rows, cols)` of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.diagonal(), vec![0, 4]);
+    /// ```
+    pub fn diagonal(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter_diagonal().cloned().collect()
+    }
+
+    /// Returns an iterator over the cells of the main diagonal, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(4, 3, 0..);
+    ///
+    /// assert_eq!(mat.iter_diagonal().count(), 3);
+    /// assert_eq!(mat.iter_diagonal().cloned().collect::<Vec<_>>(), mat.diagonal());
+    /// ```
+    pub fn iter_diagonal(&self) -> impl Iterator<Item = &T> {
+        self.iter_diagonal_offset(0)
+    }
+
+    /// Returns an iterator over the cells of an off-diagonal.  
+    /// Positive offsets select diagonals above the main one (cells `(i, i + k)`),
+    /// negative offsets select diagonals below it (cells `(i - k, i)`).
+    /// The iterator is empty if the diagonal is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 3, 0..);
+    ///
+    /// assert_eq!(mat.iter_diagonal_offset(1).cloned().collect::<Vec<_>>(), vec![1, 5]);
+    /// assert_eq!(mat.iter_diagonal_offset(-2).cloned().collect::<Vec<_>>(), vec![6]);
+    /// assert_eq!(mat.iter_diagonal_offset(3).count(), 0);
+    /// assert_eq!(mat.iter_diagonal_offset(isize::MIN).count(), 0);
+    /// assert_eq!(mat.iter_diagonal_offset(isize::MAX).count(), 0);
+    /// ```
+    pub fn iter_diagonal_offset(&self, k: isize) -> impl Iterator<Item = &T> {
+        let (row, col) = if k >= 0 {
+            (0, k.unsigned_abs())
+        } else {
+            (k.unsigned_abs(), 0)
+        };
+        // Diagonals outside of the matrix start from the first cell, so the offset cannot overflow
+        let (start, len) = if row < self.rows && col < self.cols {
+            (
+                col + row * self.cols,
+                (self.rows - row).min(self.cols - col),
+            )
+        } else {
+            (0, 0)
+        };
+
+        self.data
+            .iter()
+            .skip(start)
+            .step_by(self.cols + 1)
+            .take(len)
+    }
+
+    /// Set every cell of the main diagonal to the given value.
+    /// Only the first `min(rows, cols)` diagonal cells exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::zero(3, 3);
+    /// mat.fill_diagonal(1);
+    ///
+    /// assert_eq!(mat, Matrix::identity(3));
+    /// ```
+    pub fn fill_diagonal(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for i in 0..self.rows.min(self.cols) {
+            *self.get_mut(i, i).unwrap() = value.clone();
+        }
+    }
+
+    /// Try to set the main diagonal from a slice of values.
+    /// Returns `false` if the slice is shorter than `min(rows, cols)`,
+    /// extra values are ignored.
+    /// Returns `true` if the diagonal has been modified.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::zero(3, 3);
+    ///
+    /// assert!(mat.set_diagonal(&[1, 2, 3]));
+    /// assert_eq!(mat.get(0, 0).unwrap(), 1);
+    /// assert_eq!(mat.get(1, 1).unwrap(), 2);
+    /// assert_eq!(mat.get(2, 2).unwrap(), 3);
+    /// assert_eq!(mat.get(0, 1).unwrap(), 0);
+    ///
+    /// assert!(!mat.set_diagonal(&[4, 5]));
+    /// ```
+    pub fn set_diagonal(&mut self, values: &[T]) -> bool
+    where
+        T: Clone,
+    {
+        let len = self.rows.min(self.cols);
+        if values.len() < len {
+            return false;
+        }
+
+        for (i, value) in values.iter().take(len).enumerate() {
+            *self.get_mut(i, i).unwrap() = value.clone();
+        }
+        true
+    }
+
+    /// Add the given value to every cell of the main diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<f64> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// mat.add_to_diagonal(0.1);
+    ///
+    /// assert_eq!(mat.get(0, 0).unwrap(), 1.1);
+    /// assert_eq!(mat.get(0, 1).unwrap(), 2.0);
+    /// assert_eq!(mat.get(1, 0).unwrap(), 3.0);
+    /// assert_eq!(mat.get(1, 1).unwrap(), 4.1);
+    /// ```
+    pub fn add_to_diagonal(&mut self, lambda: T)
+    where
+        T: AddAssign + Clone,
+    {
+        for i in 0..self.rows.min(self.cols) {
+            *self.get_mut(i, i).unwrap() += lambda.clone();
+        }
+    }
+
+    /// Construct a new matrix with the given value added to every cell of the main diagonal.  
+    /// See `add_to_diagonal` for the in-place version.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::zero(2, 2);
+    /// let reg = mat.with_diagonal_added(3);
+    ///
+    /// assert_eq!(reg, Matrix::new([[3, 0], [0, 3]]));
+    /// assert_eq!(mat, Matrix::zero(2, 2));
+    /// ```
+    pub fn with_diagonal_added(&self, lambda: T) -> Matrix<T>
+    where
+        T: AddAssign + Clone,
+    {
+        let mut result = self.clone();
+        result.add_to_diagonal(lambda);
+        result
+    }
+
+    /// Returns the cells of the anti-diagonal, from the top-right corner towards the bottom-left,
+    /// there are `min(rows, cols)` of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.anti_diagonal(), vec![2, 4]);
+    /// ```
+    pub fn anti_diagonal(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        (0..self.rows.min(self.cols))
+            .map(|i| self.data[self.cols - 1 - i + i * self.cols].clone())
+            .collect()
+    }
+
+    /// Compute the sum of the anti-diagonal of a *N*x*N* Matrix.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ]);
+    ///
+    /// assert_eq!(mat.anti_trace(), Some(3 + 5 + 7));
+    /// assert!(Matrix::<i32>::zero(2, 3).anti_trace().is_none());
+    /// ```
+    pub fn anti_trace(&self) -> Option<T>
+    where
+        T: Add<Output = T> + Zero + Clone,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        Some(
+            self.anti_diagonal()
+                .into_iter()
+                .fold(T::zero(), |acc, value| acc + value),
+        )
+    }
+
+    /// Try to construct the shifted matrix `A + beta * I` of a *N*x*N* Matrix.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[5.0, 1.0], [2.0, 7.0]]);
+    ///
+    /// assert_eq!(mat.shifted(-2.0).unwrap(), Matrix::new([[3.0, 1.0], [2.0, 5.0]]));
+    /// assert!(Matrix::<f64>::zero(2, 3).shifted(1.0).is_none());
+    /// ```
+    pub fn shifted(&self, beta: T) -> Option<Matrix<T>>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let mut result = self.clone();
+        for value in result.data.iter_mut().step_by(self.cols + 1) {
+            *value = value.clone() + beta.clone();
+        }
+        Some(result)
+    }
+
+    /// Try to construct `alpha * A + beta * I` for a *N*x*N* Matrix, in a single pass.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(mat.axpy_identity(2, -1).unwrap(), Matrix::new([[1, 4], [6, 7]]));
+    /// assert!(Matrix::<i32>::zero(2, 3).axpy_identity(2, -1).is_none());
+    /// ```
+    pub fn axpy_identity(&self, alpha: T, beta: T) -> Option<Matrix<T>>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Clone,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let scaled = alpha.clone() * value.clone();
+                    if i % (self.cols + 1) == 0 {
+                        scaled + beta.clone()
+                    } else {
+                        scaled
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns an iterator over the anti-diagonals of the matrix, as used by wavefront
+    /// and dynamic-programming algorithms.  
+    /// Anti-diagonal `d` holds the cells where `row + col == d`, in increasing row order,
+    /// for `d` from `0` to `rows + cols - 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    /// let diagonals: Vec<Vec<&usize>> = mat.antidiagonals().collect();
+    ///
+    /// assert_eq!(diagonals, vec![vec![&0], vec![&1, &3], vec![&2, &4], vec![&5]]);
+    /// assert_eq!(diagonals.iter().map(Vec::len).sum::<usize>(), 6);
+    /// ```
+    pub fn antidiagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        (0..self.rows + self.cols - 1).map(move |d| {
+            let first_row = d.saturating_sub(self.cols - 1);
+            let last_row = d.min(self.rows - 1);
+            (first_row..=last_row)
+                .map(|row| &self.data[d - row + row * self.cols])
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,377 @@
+use super::{Matrix, MatrixError};
+use num_traits::{Float, Zero};
+use std::ops::{Add, Div, Mul, MulAssign, Neg};
+
+impl<T> Matrix<T> {
+    /// Multiply all cells of the matrix by the given factor, in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<f64> = Matrix::new([[2.0, 4.0], [6.0, 8.0]]);
+    /// mat.scale(0.5);
+    ///
+    /// assert_eq!(mat, Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    pub fn scale(&mut self, factor: T)
+    where
+        T: MulAssign + Clone,
+    {
+        self.data.iter_mut().for_each(|n| *n *= factor.clone());
+    }
+
+    /// Construct a new matrix with all cells multiplied by the given factor.
+    /// See `scale` for the in-place version.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(mat.scaled(3), Matrix::new([[3, 6], [9, 12]]));
+    /// ```
+    pub fn scaled(&self, factor: T) -> Matrix<T>
+    where
+        T: MulAssign + Clone,
+    {
+        let mut result = self.clone();
+        result.scale(factor);
+        result
+    }
+
+    /// Try to negate all cells of the requested row, in place.  
+    /// Returns `false` if given row is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    ///
+    /// assert!(mat.negate_row(1));
+    /// assert_eq!(mat, Matrix::from_iter(3, 2, vec![1, 2, -3, -4, 5, 6]));
+    ///
+    /// assert!(!mat.negate_row(3));
+    /// ```
+    pub fn negate_row(&mut self, row: usize) -> bool
+    where
+        T: Neg<Output = T> + Clone,
+    {
+        if row >= self.rows {
+            return false;
+        }
+
+        let start = row * self.cols;
+        for value in &mut self.data[start..start + self.cols] {
+            *value = -value.clone();
+        }
+        true
+    }
+
+    /// Try to negate all cells of the requested column, in place.  
+    /// Returns `false` if given column is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    ///
+    /// assert!(mat.negate_col(0));
+    /// assert_eq!(mat, Matrix::from_iter(3, 2, vec![-1, 2, -3, 4, -5, 6]));
+    ///
+    /// assert!(!mat.negate_col(2));
+    /// ```
+    pub fn negate_col(&mut self, col: usize) -> bool
+    where
+        T: Neg<Output = T> + Clone,
+    {
+        if col >= self.cols {
+            return false;
+        }
+
+        for value in self.data.iter_mut().skip(col).step_by(self.cols) {
+            *value = -value.clone();
+        }
+        true
+    }
+
+    /// Set every cell for which the predicate returns `true` to the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<f64> = Matrix::new([[-1.0, 2.0], [f64::NAN, -0.5]]);
+    /// mat.replace_where(|n| *n < 0.0 || n.is_nan(), 0.0);
+    ///
+    /// assert_eq!(mat, Matrix::new([[0.0, 2.0], [0.0, 0.0]]));
+    /// ```
+    pub fn replace_where<F: FnMut(&T) -> bool>(&mut self, mut pred: F, value: T)
+    where
+        T: Clone,
+    {
+        for cell in self.data.iter_mut().filter(|cell| pred(cell)) {
+            *cell = value.clone();
+        }
+    }
+
+    /// Construct a new matrix by applying `f` to the corresponding cells of three matrices,
+    /// in a single pass.  
+    /// Returns `None` if the matrices do not all have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::from_iter(2, 3, 0..);
+    /// let b: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    /// let c: Matrix<i32> = Matrix::from_iter(2, 3, 2..);
+    ///
+    /// let fused = a.zip3_map(&b, &c, |a, b, c| a * b + c).unwrap();
+    /// let hadamard = Matrix::from_iter(2, 3, a.iter().zip(b.iter()).map(|(a, b)| a * b));
+    ///
+    /// assert_eq!(fused, &hadamard + &c);
+    ///
+    /// assert!(a.zip3_map(&b, &Matrix::zero(3, 2), |a, _, _| *a).is_none());
+    /// ```
+    pub fn zip3_map<F: FnMut(&T, &T, &T) -> T>(
+        &self,
+        b: &Matrix<T>,
+        c: &Matrix<T>,
+        mut f: F,
+    ) -> Option<Matrix<T>> {
+        if (self.rows, self.cols) != (b.rows, b.cols) || (self.rows, self.cols) != (c.rows, c.cols)
+        {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(b.data.iter())
+                .zip(c.data.iter())
+                .map(|((a, b), c)| f(a, b, c))
+                .collect(),
+        })
+    }
+
+    /// Construct the element-wise product of two matrices, broadcasting `other` if needed:
+    /// - if both matrices have the same shape, corresponding cells are multiplied,
+    /// - if `other` is a *1*x*cols* row, it is multiplied with every row,
+    /// - if `other` is a *rows*x*1* column, it is multiplied with every column.
+    ///
+    /// Returns `None` for any other shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// let full = Matrix::from_iter(2, 3, vec![2, 0, 1, 1, 0, 2]);
+    /// assert_eq!(mat.broadcast_mul(&full).unwrap(), Matrix::from_iter(2, 3, vec![2, 0, 3, 4, 0, 12]));
+    ///
+    /// let row = Matrix::from_iter(1, 3, vec![1, 10, 100]);
+    /// assert_eq!(mat.broadcast_mul(&row).unwrap(), Matrix::from_iter(2, 3, vec![1, 20, 300, 4, 50, 600]));
+    ///
+    /// let col = Matrix::from_iter(2, 1, vec![-1, 2]);
+    /// assert_eq!(mat.broadcast_mul(&col).unwrap(), Matrix::from_iter(2, 3, vec![-1, -2, -3, 8, 10, 12]));
+    ///
+    /// assert!(mat.broadcast_mul(&Matrix::zero(3, 2)).is_none());
+    /// ```
+    pub fn broadcast_mul(&self, other: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        let index: fn(usize, usize, usize) -> usize =
+            if (other.rows, other.cols) == (self.rows, self.cols) {
+                |row, col, cols| col + row * cols
+            } else if (other.rows, other.cols) == (1, self.cols) {
+                |_, col, _| col
+            } else if (other.rows, other.cols) == (self.rows, 1) {
+                |row, _, _| row
+            } else {
+                return None;
+            };
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let other = &other.data[index(i / self.cols, i % self.cols, self.cols)];
+                    value.clone() * other.clone()
+                })
+                .collect(),
+        })
+    }
+
+    /// Try to compute the weighted sum `weights[0] * mats[0] + weights[1] * mats[1] + ...`
+    /// in a single pass, without intermediate matrices.  
+    /// Returns `None` if there are no matrices, if the number of weights does not match
+    /// or if the matrices do not all have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new([[1.0, 0.0], [0.0, 1.0]]);
+    /// let b: Matrix<f64> = Matrix::new([[0.0, 10.0], [10.0, 1.0]]);
+    ///
+    /// let blend = Matrix::weighted_sum(&[a.clone(), b.clone()], &[0.3, 0.7]).unwrap();
+    /// for (value, expected) in blend.iter().zip([0.3, 7.0, 7.0, 1.0]) {
+    ///     assert!((value - expected).abs() < 1e-12);
+    /// }
+    ///
+    /// assert!(Matrix::weighted_sum(&[a.clone(), b], &[1.0]).is_none());
+    /// assert!(Matrix::weighted_sum(&[a, Matrix::zero(2, 3)], &[1.0, 1.0]).is_none());
+    /// assert!(Matrix::<f64>::weighted_sum(&[], &[]).is_none());
+    /// ```
+    pub fn weighted_sum(mats: &[Matrix<T>], weights: &[T]) -> Option<Matrix<T>>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
+    {
+        let first = mats.first()?;
+        if mats.len() != weights.len()
+            || mats
+                .iter()
+                .any(|mat| (mat.rows, mat.cols) != (first.rows, first.cols))
+        {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: first.rows,
+            cols: first.cols,
+            data: (0..first.data.len())
+                .map(|i| {
+                    mats.iter()
+                        .zip(weights)
+                        .fold(T::zero(), |acc, (mat, weight)| {
+                            acc + weight.clone() * mat.data[i].clone()
+                        })
+                })
+                .collect(),
+        })
+    }
+
+    /// Construct the element-wise (Hadamard) product of two matrices.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::DimensionMismatch` if the matrices do not have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, MatrixError};
+    ///
+    /// let a: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    /// let b: Matrix<i32> = Matrix::new([[5, 6], [7, 8]]);
+    ///
+    /// assert_eq!(a.checked_hadamard(&b), Ok(Matrix::new([[5, 12], [21, 32]])));
+    /// assert_eq!(
+    ///     a.checked_hadamard(&Matrix::zero(2, 3)),
+    ///     Err(MatrixError::DimensionMismatch { left: (2, 2), right: (2, 3) })
+    /// );
+    /// ```
+    pub fn checked_hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        self.zip_map(other, |a, b| a.clone() * b.clone())
+            .ok_or(MatrixError::DimensionMismatch {
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            })
+    }
+
+    /// Try to construct the element-wise quotient of two matrices.  
+    /// Returns `None` if the matrices do not have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new([[1.0, 4.0], [9.0, 16.0]]);
+    /// let b: Matrix<f64> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(a.hadamard_div(&b).unwrap(), b);
+    /// assert!(a.hadamard_div(&Matrix::zero(2, 3)).is_none());
+    /// ```
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Div<Output = T> + Clone,
+    {
+        self.zip_map(other, |a, b| a.clone() / b.clone())
+    }
+
+    /// Try to construct the element-wise quotient of two matrices, using `default`
+    /// for the cells where `other` is zero instead of an infinite or NaN value.  
+    /// Returns `None` if the matrices do not have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new([[1.0, 4.0], [9.0, 0.0]]);
+    /// let b: Matrix<f64> = Matrix::new([[1.0, 0.0], [3.0, 0.0]]);
+    ///
+    /// assert_eq!(a.hadamard_div_safe(&b, 0.0).unwrap(), Matrix::new([[1.0, 0.0], [3.0, 0.0]]));
+    /// assert!(a.hadamard_div(&b).unwrap().get(0, 1).unwrap().is_infinite());
+    /// ```
+    pub fn hadamard_div_safe(&self, other: &Matrix<T>, default: T) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        self.zip_map(other, |&a, &b| if b.is_zero() { default } else { a / b })
+    }
+
+    /// Try to linearly interpolate between two matrices, computing `(1 - t) * self + t * other`
+    /// for every cell.  
+    /// Returns `None` if the matrices do not have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new([[0.0, 2.0], [4.0, -1.0]]);
+    /// let b: Matrix<f64> = Matrix::new([[1.0, 4.0], [0.0, 1.0]]);
+    ///
+    /// assert_eq!(a.lerp(&b, 0.0).unwrap(), a);
+    /// assert_eq!(a.lerp(&b, 1.0).unwrap(), b);
+    /// assert_eq!(a.lerp(&b, 0.5).unwrap(), Matrix::new([[0.5, 3.0], [2.0, 0.0]]));
+    ///
+    /// assert!(a.lerp(&Matrix::zero(2, 3), 0.5).is_none());
+    /// ```
+    pub fn lerp(&self, other: &Matrix<T>, t: T) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        self.zip_map(other, |&a, &b| (T::one() - t) * a + t * b)
+    }
+
+    fn zip_map<F: FnMut(&T, &T) -> T>(&self, other: &Matrix<T>, mut f: F) -> Option<Matrix<T>> {
+        if (self.rows, self.cols) != (other.rows, other.cols) {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        })
+    }
+}
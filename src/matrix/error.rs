@@ -0,0 +1,149 @@
+use super::Axis;
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the fallible matrix operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The requested number of rows or columns is `0`.
+    EmptyDimension {
+        /// The requested number of rows.
+        rows: usize,
+        /// The requested number of columns.
+        cols: usize,
+    },
+    /// The number of provided values does not match `rows * cols`.
+    LengthMismatch {
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        found: usize,
+    },
+    /// The shapes of the operands are not compatible.
+    DimensionMismatch {
+        /// The `(rows, cols)` shape of the left operand.
+        left: (usize, usize),
+        /// The `(rows, cols)` shape of the right operand.
+        right: (usize, usize),
+    },
+    /// The operation requires a square matrix.
+    NotSquare {
+        /// The number of rows of the matrix.
+        rows: usize,
+        /// The number of columns of the matrix.
+        cols: usize,
+    },
+    /// The requested row or column is outside of the matrix.
+    IndexOutOfRange {
+        /// Whether a row or a column was requested.
+        axis: Axis,
+        /// The requested index.
+        index: usize,
+        /// The number of rows or columns of the matrix.
+        len: usize,
+    },
+    /// The matrix is singular, or does not have full column rank.
+    Singular,
+    /// The system has fewer equations (rows) than unknowns (columns).
+    Underdetermined {
+        /// The number of rows of the matrix.
+        rows: usize,
+        /// The number of columns of the matrix.
+        cols: usize,
+    },
+    /// The matrix is larger than the allowed limit.
+    TooLarge {
+        /// The dimension of the matrix.
+        dim: usize,
+        /// The largest allowed dimension.
+        max_dim: usize,
+    },
+    /// The byte buffer does not have the length announced by its header.
+    InvalidBytes {
+        /// The expected number of bytes.
+        expected: usize,
+        /// The actual number of bytes.
+        found: usize,
+    },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixError::EmptyDimension { rows, cols } => {
+                write!(f, "cannot construct an empty {}x{} matrix", rows, cols)
+            }
+            MatrixError::LengthMismatch { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+            MatrixError::DimensionMismatch { left, right } => {
+                write!(
+                    f,
+                    "incompatible shapes {}x{} and {}x{}",
+                    left.0, left.1, right.0, right.1
+                )
+            }
+            MatrixError::NotSquare { rows, cols } => {
+                write!(
+                    f,
+                    "expected a square matrix, found a {}x{} matrix",
+                    rows, cols
+                )
+            }
+            MatrixError::IndexOutOfRange { axis, index, len } => {
+                let (name, names) = match axis {
+                    Axis::Rows => ("row", "rows"),
+                    Axis::Cols => ("column", "columns"),
+                };
+                write!(
+                    f,
+                    "{} {} is out of range for a matrix with {} {}",
+                    name, index, len, names
+                )
+            }
+            MatrixError::Singular => write!(f, "the matrix is singular or rank-deficient"),
+            MatrixError::Underdetermined { rows, cols } => write!(
+                f,
+                "the system is underdetermined, with {} equations for {} unknowns",
+                rows, cols
+            ),
+            MatrixError::TooLarge { dim, max_dim } => {
+                write!(
+                    f,
+                    "matrix dimension {} exceeds the limit of {}",
+                    dim, max_dim
+                )
+            }
+            MatrixError::InvalidBytes { expected, found } => {
+                write!(f, "expected {} bytes, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for MatrixError {}
+
+/// Error returned when accessing a cell outside of the matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfBounds {
+    /// The requested row.
+    pub row: usize,
+    /// The requested column.
+    pub col: usize,
+    /// The number of rows in the matrix.
+    pub rows: usize,
+    /// The number of columns in the matrix.
+    pub cols: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cell ({}, {}) is out of bounds for a {}x{} matrix",
+            self.row, self.col, self.rows, self.cols
+        )
+    }
+}
+
+impl Error for OutOfBounds {}
@@ -0,0 +1,70 @@
+use super::Matrix;
+use num_traits::Float;
+use std::fmt::Display;
+
+impl<T> Matrix<T> {
+    /// Format the matrix as a LaTeX `pmatrix` environment.  
+    /// Cells are separated by `&` and rows are terminated by `\\`, except the last one.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(
+    ///     mat.to_latex(),
+    ///     "\\begin{pmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{pmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self) -> String
+    where
+        T: Display,
+    {
+        self.latex_with(|value| value.to_string())
+    }
+
+    /// Format a float matrix as a LaTeX `pmatrix` environment,
+    /// printing the cells with `precision` decimals.  
+    /// Only float cells are accepted, since a precision would truncate strings and is ignored
+    /// by integers. Other cells are formatted with `to_latex`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[1.0, 0.5], [1.0 / 3.0, -2.0]]);
+    ///
+    /// assert_eq!(
+    ///     mat.to_latex_with(2),
+    ///     "\\begin{pmatrix}\n1.00 & 0.50 \\\\\n0.33 & -2.00\n\\end{pmatrix}"
+    /// );
+    /// ```
+    ///
+    /// Non-float cells do not compile:
+    /// ```compile_fail
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<&str> = Matrix::new([["alpha", "beta"]]);
+    /// mat.to_latex_with(2);
+    /// ```
+    pub fn to_latex_with(&self, precision: usize) -> String
+    where
+        T: Float + Display,
+    {
+        self.latex_with(|value| format!("{:.*}", precision, value))
+    }
+
+    fn latex_with<F: FnMut(&T) -> String>(&self, mut format: F) -> String {
+        let rows: Vec<String> = self
+            .data
+            .chunks(self.cols)
+            .map(|row| row.iter().map(&mut format).collect::<Vec<_>>().join(" & "))
+            .collect();
+
+        format!(
+            "\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}",
+            rows.join(" \\\\\n")
+        )
+    }
+}
@@ -28,3 +28,174 @@ impl<'a, T> IntoIterator for &'a mut Matrix<T> {
         self.data.iter_mut()
     }
 }
+
+/// Collects an iterator of rows into a matrix.
+///
+/// # Panics
+/// Panics if the iterator is empty, if the rows are empty or if they do not all have the same length.
+///
+/// # Examples
+/// ```
+/// use simple_matrix::Matrix;
+///
+/// let rows = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+/// let mat: Matrix<i32> = rows.into_iter().collect();
+///
+/// assert_eq!(mat, Matrix::from_iter(3, 2, 1..));
+/// ```
+impl<T> FromIterator<Vec<T>> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let mut rows = 0;
+        let mut cols = 0;
+        let mut data = Vec::new();
+
+        for row in iter {
+            if rows == 0 {
+                cols = row.len();
+            }
+            assert_eq!(
+                row.len(),
+                cols,
+                "row {} has {} values, expected {}",
+                rows,
+                row.len(),
+                cols
+            );
+            data.extend(row);
+            rows += 1;
+        }
+        assert!(rows > 0 && cols > 0, "cannot collect an empty matrix");
+
+        Matrix { rows, cols, data }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Returns an iterator over the cells, column by column.  
+    /// The row-major order is available through `iter`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(mat.iter_col_major().cloned().collect::<Vec<_>>(), vec![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn iter_col_major(&self) -> impl Iterator<Item = &T> {
+        let (rows, cols) = (self.rows, self.cols);
+        (0..self.data.len()).map(move |i| &self.data[i / rows + (i % rows) * cols])
+    }
+
+    /// Returns an iterator over mutable references to the cells, column by column.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::zero(2, 3);
+    /// for (cell, value) in mat.iter_col_major_mut().zip(1..) {
+    ///     *cell = value;
+    /// }
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, vec![1, 3, 5, 2, 4, 6]));
+    /// ```
+    pub fn iter_col_major_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let len = self.data.len();
+        let mut rows: Vec<_> = self
+            .data
+            .chunks_mut(self.cols)
+            .map(|row| row.iter_mut())
+            .collect();
+
+        (0..len).map(move |i| {
+            let row = i % rows.len();
+            rows[row].next().unwrap()
+        })
+    }
+
+    /// Returns an iterator over the rows of the matrix, as contiguous slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    /// let sums: Vec<i32> = mat.row_slices().map(|row| row.iter().sum()).collect();
+    ///
+    /// assert_eq!(sums, vec![3, 7, 11]);
+    /// ```
+    pub fn row_slices(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.cols)
+    }
+
+    /// Returns an iterator over the rows of the matrix, as contiguous mutable slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    /// mat.row_slices_mut().for_each(|row| row.reverse());
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(3, 2, vec![2, 1, 4, 3, 6, 5]));
+    /// ```
+    pub fn row_slices_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.data.chunks_mut(self.cols)
+    }
+
+    /// Try to iterate over the corresponding rows of two matrices, as pairs of slices.  
+    /// The matrices may have a different number of columns.  
+    /// Returns `None` if they do not have the same number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::from_iter(3, 2, 0..);
+    /// let b: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+    ///
+    /// for (i, (a, b)) in a.zip_rows(&b).unwrap().enumerate() {
+    ///     assert_eq!(a.len(), 2);
+    ///     assert_eq!(b.len(), 4);
+    ///     assert_eq!(b[0], 4 * i as i32);
+    /// }
+    ///
+    /// assert!(a.zip_rows(&Matrix::zero(2, 3)).is_none());
+    /// ```
+    pub fn zip_rows<'a>(
+        &'a self,
+        other: &'a Matrix<T>,
+    ) -> Option<impl Iterator<Item = (&'a [T], &'a [T])>> {
+        if self.rows != other.rows {
+            return None;
+        }
+
+        Some(self.row_slices().zip(other.row_slices()))
+    }
+
+    /// Consume the matrix and return an iterator over its cells with their coordinates,
+    /// as `(row, col, value)` in row-major order, without cloning them.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<String> = Matrix::from_iter(2, 2, ["a", "b", "c", "d"].map(String::from));
+    /// let cells: Vec<_> = mat.into_enumerate().collect();
+    ///
+    /// assert_eq!(cells, vec![
+    ///     (0, 0, String::from("a")),
+    ///     (0, 1, String::from("b")),
+    ///     (1, 0, String::from("c")),
+    ///     (1, 1, String::from("d")),
+    /// ]);
+    /// ```
+    pub fn into_enumerate(self) -> impl Iterator<Item = (usize, usize, T)> {
+        let cols = self.cols;
+        self.data
+            .into_iter()
+            .enumerate()
+            .map(move |(i, value)| (i / cols, i % cols, value))
+    }
+}
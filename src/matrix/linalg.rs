@@ -0,0 +1,1309 @@
+use super::{Axis, Matrix, MatrixError};
+use num_traits::{Float, One, Zero};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+impl<T> Matrix<T> {
+    /// Compute the determinant of a *N*x*N* Matrix.  
+    /// Uses fraction-free (Bareiss) elimination, whose divisions are always exact,
+    /// so the result is also exact for integer cells.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([
+    ///     [2, -3, 1],
+    ///     [2, 0, -1],
+    ///     [1, 4, 5],
+    /// ]);
+    /// assert_eq!(mat.determinant(), Some(49));
+    ///
+    /// let swapped: Matrix<f64> = Matrix::new([[0.0, 1.0], [1.0, 0.0]]);
+    /// assert_eq!(swapped.determinant(), Some(-1.0));
+    ///
+    /// assert!(Matrix::<i32>::zero(2, 3).determinant().is_none());
+    /// ```
+    pub fn determinant(&self) -> Option<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut matrix = self.clone();
+        let mut negate = false;
+        let mut previous = T::one();
+
+        for k in 0..n - 1 {
+            if matrix.data[k + k * n].is_zero() {
+                match (k + 1..n).find(|&i| !matrix.data[k + i * n].is_zero()) {
+                    Some(i) => {
+                        matrix.swap_rows(i, k);
+                        negate = !negate;
+                    }
+                    None => return Some(T::zero()),
+                }
+            }
+
+            let pivot = matrix.data[k + k * n].clone();
+            for i in k + 1..n {
+                let factor = matrix.data[k + i * n].clone();
+                for j in k + 1..n {
+                    let value = (matrix.data[j + i * n].clone() * pivot.clone()
+                        - factor.clone() * matrix.data[j + k * n].clone())
+                        / previous.clone();
+                    matrix.data[j + i * n] = value;
+                }
+            }
+            previous = pivot;
+        }
+
+        let det = matrix.data[n * n - 1].clone();
+        Some(if negate { -det } else { det })
+    }
+
+    /// Compute the determinant of a *N*x*N* Matrix like `determinant`,
+    /// but refuse to start the `O(N^3)` elimination if `N` is larger than `max_dim`.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::NotSquare` if the matrix is not square.  
+    /// Returns `MatrixError::TooLarge` if the matrix has more than `max_dim` rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, MatrixError};
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[2, 1], [1, 3]]);
+    /// assert_eq!(mat.determinant_with_limit(2), Ok(5));
+    ///
+    /// let large: Matrix<i32> = Matrix::identity(100);
+    /// assert_eq!(
+    ///     large.determinant_with_limit(64),
+    ///     Err(MatrixError::TooLarge { dim: 100, max_dim: 64 })
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Matrix::<i32>::zero(2, 3).determinant_with_limit(64),
+    ///     Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    /// );
+    /// ```
+    pub fn determinant_with_limit(&self, max_dim: usize) -> Result<T, MatrixError>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        if self.rows > max_dim {
+            return Err(MatrixError::TooLarge {
+                dim: self.rows,
+                max_dim,
+            });
+        }
+
+        Ok(self.determinant().unwrap())
+    }
+
+    /// Estimate the condition number of a *N*x*N* Matrix as `||A||_1 * ||A^-1||_1`,
+    /// where `||.||_1` is the maximum absolute column sum.
+    /// Large values mean that solving systems with this matrix loses precision.
+    /// Returns `None` if the matrix is not square or is singular.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let ident: Matrix<f64> = Matrix::identity(3);
+    /// assert!((ident.condition_number_1norm().unwrap() - 1.0).abs() < 1e-12);
+    ///
+    /// let almost_singular: Matrix<f64> = Matrix::new([[1.0, 1.0], [1.0, 1.0001]]);
+    /// assert!(almost_singular.condition_number_1norm().unwrap() > 1e4);
+    ///
+    /// let singular: Matrix<f64> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!(singular.condition_number_1norm().is_none());
+    /// ```
+    pub fn condition_number_1norm(&self) -> Option<T>
+    where
+        T: Float,
+    {
+        let inverse = self.inverse()?;
+        Some(self.norm_1() * inverse.norm_1())
+    }
+
+    /// Compute the traces of the first `k` powers of a *N*x*N* Matrix,
+    /// `[tr(A), tr(A^2), ..., tr(A^k)]`, as used by Newton's identities.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(mat.trace_powers(3), Some(vec![5, 29, 155]));
+    /// assert_eq!(mat.trace_powers(0), Some(vec![]));
+    ///
+    /// assert!(Matrix::<i32>::zero(2, 3).trace_powers(1).is_none());
+    /// ```
+    pub fn trace_powers(&self, k: usize) -> Option<Vec<T>>
+    where
+        T: Add<Output = T> + Zero + Clone,
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let trace = |mat: &Matrix<T>| {
+            mat.iter_diagonal()
+                .fold(T::zero(), |acc, value| acc + value.clone())
+        };
+
+        let mut traces = Vec::with_capacity(k);
+        let mut power = self.clone();
+        for i in 0..k {
+            if i > 0 {
+                power = &power * self;
+            }
+            traces.push(trace(&power));
+        }
+
+        Some(traces)
+    }
+
+    /// Try to compute `self^T * other` without constructing the transpose,
+    /// by multiplying the columns of both matrices.  
+    /// Returns `None` if both matrices do not have the same number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    /// let b: Matrix<i32> = Matrix::from_iter(3, 4, -5..);
+    ///
+    /// assert_eq!(a.transpose_mul(&b).unwrap(), &a.transpose() * &b);
+    ///
+    /// assert!(a.transpose_mul(&Matrix::zero(2, 3)).is_none());
+    /// ```
+    pub fn transpose_mul(&self, other: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
+    {
+        if self.rows != other.rows {
+            return None;
+        }
+
+        let mut result: Matrix<T> = Matrix::zero(self.cols, other.cols);
+        for (lhs, rhs) in self
+            .data
+            .chunks(self.cols)
+            .zip(other.data.chunks(other.cols))
+        {
+            for (row, a) in lhs.iter().enumerate() {
+                for (col, b) in rhs.iter().enumerate() {
+                    let cell = &mut result.data[col + row * other.cols];
+                    *cell = cell.clone() + a.clone() * b.clone();
+                }
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Try to write the product `self * rhs` into `dst`, reusing its allocation.
+    /// Returns `false` (and leaves `dst` untouched) if `self` does not have as many columns
+    /// as `rhs` has rows, or `dst` is not a *self.rows()*x*rhs.cols()* matrix.
+    /// Returns `true` if `dst` has been overwritten.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    /// let b: Matrix<i32> = Matrix::from_iter(3, 4, -5..);
+    /// let mut dst: Matrix<i32> = Matrix::zero(2, 4);
+    ///
+    /// assert!(a.mul_into(&b, &mut dst));
+    /// assert_eq!(dst, &a * &b);
+    ///
+    /// let mut wrong: Matrix<i32> = Matrix::zero(4, 2);
+    /// assert!(!a.mul_into(&b, &mut wrong));
+    /// assert_eq!(wrong, Matrix::zero(4, 2));
+    /// assert!(!a.mul_into(&a, &mut dst));
+    /// ```
+    pub fn mul_into(&self, rhs: &Matrix<T>, dst: &mut Matrix<T>) -> bool
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
+    {
+        if self.cols != rhs.rows || dst.rows != self.rows || dst.cols != rhs.cols {
+            return false;
+        }
+
+        for (row, dst_row) in dst.data.chunks_mut(rhs.cols).enumerate() {
+            let lhs = &self.data[row * self.cols..(row + 1) * self.cols];
+            for (col, cell) in dst_row.iter_mut().enumerate() {
+                *cell = lhs
+                    .iter()
+                    .zip(rhs.data.iter().skip(col).step_by(rhs.cols))
+                    .fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone());
+            }
+        }
+        true
+    }
+
+    /// Compute the product `self * rhs` for a banded `self`, whose non-zero cells `(i, j)` all
+    /// satisfy `|i - j| <= self_bandwidth`, skipping the products with cells outside of the band.
+    /// For a tridiagonal matrix `self_bandwidth` is `1`, the product then costs
+    /// `O(rows * rhs.cols)` instead of `O(rows * cols * rhs.cols)`.
+    /// Cells of `self` outside of the band are assumed to be zero, so the result only matches
+    /// `&self * rhs` if `self` is really banded.  
+    /// Rows of a tall matrix lying entirely below the band are filled with the product
+    /// of their first (zero) cell, without looping over the row.  
+    /// A bandwidth of `usize::MAX` is the full band, like a regular product.
+    ///
+    /// # Panics
+    /// Panics if `self` does not have as many columns as `rhs` has rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let tridiagonal: Matrix<i32> = Matrix::new([
+    ///     [2, -1, 0, 0],
+    ///     [-1, 2, -1, 0],
+    ///     [0, -1, 2, -1],
+    ///     [0, 0, -1, 2],
+    /// ]);
+    /// let rhs: Matrix<i32> = Matrix::from_iter(4, 3, 1..);
+    ///
+    /// assert_eq!(tridiagonal.mul_banded(&rhs, 1), &tridiagonal * &rhs);
+    ///
+    /// // Rows below the band are zero
+    /// let tall: Matrix<i32> = Matrix::from_fn(5, 2, |row, col| if row == col { 3 } else { 0 });
+    /// let rhs: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    /// assert_eq!(tall.mul_banded(&rhs, 0), &tall * &rhs);
+    ///
+    /// let full: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    /// assert_eq!(full.mul_banded(&rhs, usize::MAX), &full * &rhs);
+    /// ```
+    pub fn mul_banded(&self, rhs: &Matrix<T>, self_bandwidth: usize) -> Matrix<T>
+    where
+        T: Add<Output = T>,
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+    {
+        assert!(self.cols == rhs.rows);
+
+        let mut data = Vec::with_capacity(self.rows * rhs.cols);
+        for row in 0..self.rows {
+            let start = row.saturating_sub(self_bandwidth).min(self.cols);
+            let end = row
+                .saturating_add(self_bandwidth)
+                .saturating_add(1)
+                .min(self.cols);
+            // Rows below the band only hold zeros, a single product with one of them gives the zero
+            let band = if start < end { start..end } else { 0..1 };
+
+            for col in 0..rhs.cols {
+                let mut products = band
+                    .clone()
+                    .map(|k| &self.data[k + row * self.cols] * &rhs.data[col + k * rhs.cols]);
+                let first = products.next().unwrap();
+                data.push(products.fold(first, |acc, product| acc + product));
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            data,
+        }
+    }
+
+    /// Try to contract `self` along `self_axis` with `other` along `other_axis`,
+    /// summing the products of the cells sharing the same index on the contracted axes.  
+    /// The result is indexed by the remaining axis of `self`, then the remaining axis of `other`:
+    /// - `contract(other, Axis::Cols, Axis::Rows)` is `self * other`,
+    /// - `contract(other, Axis::Rows, Axis::Rows)` is `self^T * other`,
+    /// - `contract(other, Axis::Cols, Axis::Cols)` is `self * other^T`,
+    /// - `contract(other, Axis::Rows, Axis::Cols)` is `self^T * other^T`.
+    ///
+    /// Returns `None` if the contracted axes do not have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Axis, Matrix};
+    ///
+    /// let a: Matrix<i32> = Matrix::from_iter(2, 3, 0..);
+    /// let b: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    ///
+    /// assert_eq!(a.contract(&b, Axis::Cols, Axis::Rows).unwrap(), &a * &b);
+    /// assert_eq!(a.contract(&b, Axis::Rows, Axis::Cols).unwrap(), &a.transpose() * &b.transpose());
+    /// assert_eq!(a.contract(&a, Axis::Cols, Axis::Cols).unwrap(), &a * &a.transpose());
+    ///
+    /// assert!(a.contract(&b, Axis::Rows, Axis::Rows).is_none());
+    /// ```
+    pub fn contract(
+        &self,
+        other: &Matrix<T>,
+        self_axis: Axis,
+        other_axis: Axis,
+    ) -> Option<Matrix<T>>
+    where
+        T: Add<Output = T>,
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+    {
+        // Length of the contracted and of the remaining axis, and the strides along both
+        let layout = |mat: &Matrix<T>, axis| match axis {
+            Axis::Rows => (mat.rows, mat.cols, mat.cols, 1),
+            Axis::Cols => (mat.cols, mat.rows, 1, mat.cols),
+        };
+        let (len, rows, lhs_step, lhs_stride) = layout(self, self_axis);
+        let (other_len, cols, rhs_step, rhs_stride) = layout(other, other_axis);
+        if len != other_len {
+            return None;
+        }
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                let lhs = |k: usize| &self.data[row * lhs_stride + k * lhs_step];
+                let rhs = |k: usize| &other.data[col * rhs_stride + k * rhs_step];
+                let acc = (1..len).fold(lhs(0) * rhs(0), |acc, k| acc + lhs(k) * rhs(k));
+                data.push(acc);
+            }
+        }
+
+        Some(Matrix { rows, cols, data })
+    }
+
+    /// Try to construct the symmetric part `(A + A^T) / 2` of a *N*x*N* Matrix.  
+    /// Returns `None` if the matrix is not square.  
+    /// See `skew_symmetric_part` for the other half of the decomposition.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 9.0],
+    /// ]);
+    /// let sym = mat.symmetric_part().unwrap();
+    /// let skew = mat.skew_symmetric_part().unwrap();
+    ///
+    /// assert_eq!(sym, sym.transpose());
+    /// assert_eq!(skew, -skew.transpose());
+    /// for (value, expected) in (&sym + &skew).iter().zip(mat.iter()) {
+    ///     assert!((value - expected).abs() < 1e-12);
+    /// }
+    ///
+    /// assert!(Matrix::<f64>::zero(2, 3).symmetric_part().is_none());
+    /// ```
+    pub fn symmetric_part(&self) -> Option<Matrix<T>>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + One + Clone,
+    {
+        self.transpose_combine(|a, b| a + b)
+    }
+
+    /// Try to construct the skew-symmetric part `(A - A^T) / 2` of a *N*x*N* Matrix.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[1.0, 4.0], [2.0, 3.0]]);
+    ///
+    /// assert_eq!(mat.skew_symmetric_part().unwrap(), Matrix::new([[0.0, 1.0], [-1.0, 0.0]]));
+    /// assert!(Matrix::<f64>::zero(2, 3).skew_symmetric_part().is_none());
+    /// ```
+    pub fn skew_symmetric_part(&self) -> Option<Matrix<T>>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + One + Clone,
+    {
+        self.transpose_combine(|a, b| a - b)
+    }
+
+    // Cell (i, j) is `f(A[i, j], A[j, i]) / 2`
+    fn transpose_combine<F: Fn(T, T) -> T>(&self, f: F) -> Option<Matrix<T>>
+    where
+        T: Add<Output = T> + Div<Output = T> + One + Clone,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let n = self.rows;
+        let two = T::one() + T::one();
+        Some(Matrix {
+            rows: n,
+            cols: n,
+            data: (0..n * n)
+                .map(|i| {
+                    let (row, col) = (i / n, i % n);
+                    f(self.data[i].clone(), self.data[row + col * n].clone()) / two.clone()
+                })
+                .collect(),
+        })
+    }
+
+    /// Construct the Kronecker product `A ⊗ B`, a *(A.rows * B.rows)*x*(A.cols * B.cols)* matrix
+    /// made of the blocks `A[i, j] * B`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    /// let b: Matrix<i32> = Matrix::new([[0, 5], [6, 7]]);
+    ///
+    /// assert_eq!(a.kronecker(&b), Matrix::new([
+    ///     [0, 5, 0, 10],
+    ///     [6, 7, 12, 14],
+    ///     [0, 15, 0, 20],
+    ///     [18, 21, 24, 28],
+    /// ]));
+    /// ```
+    pub fn kronecker(&self, other: &Matrix<T>) -> Matrix<T>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        let rows = self.rows * other.rows;
+        let cols = self.cols * other.cols;
+
+        Matrix {
+            rows,
+            cols,
+            data: (0..rows * cols)
+                .map(|i| {
+                    let (row, col) = (i / cols, i % cols);
+                    let a = &self.data[col / other.cols + row / other.rows * self.cols];
+                    let b = &other.data[col % other.cols + row % other.rows * other.cols];
+                    a.clone() * b.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Construct the Kronecker product `I_n ⊗ A`, the block-diagonal matrix with `n` copies of `A`
+    /// on its diagonal, without any multiplication.
+    ///
+    /// # Panics
+    /// Panics if `n` is equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(a.kron_left_identity(2), Matrix::new([
+    ///     [1, 2, 3, 0, 0, 0],
+    ///     [4, 5, 6, 0, 0, 0],
+    ///     [0, 0, 0, 1, 2, 3],
+    ///     [0, 0, 0, 4, 5, 6],
+    /// ]));
+    /// assert_eq!(a.kron_left_identity(3), Matrix::<i32>::identity(3).kronecker(&a));
+    /// ```
+    pub fn kron_left_identity(&self, n: usize) -> Matrix<T>
+    where
+        T: Zero + Clone,
+    {
+        let mut result: Matrix<T> = Matrix::zero(n * self.rows, n * self.cols);
+        for block in 0..n {
+            for (row, cells) in self.data.chunks(self.cols).enumerate() {
+                let start = block * self.cols + (block * self.rows + row) * result.cols;
+                result.data[start..start + self.cols].clone_from_slice(cells);
+            }
+        }
+        result
+    }
+
+    /// Construct the Kronecker product `A ⊗ I_n`, where every cell `A[i, j]` becomes
+    /// the *n*x*n* block `A[i, j] * I_n`, without any multiplication.
+    ///
+    /// # Panics
+    /// Panics if `n` is equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(a.kron_right_identity(2), Matrix::new([
+    ///     [1, 0, 2, 0],
+    ///     [0, 1, 0, 2],
+    ///     [3, 0, 4, 0],
+    ///     [0, 3, 0, 4],
+    /// ]));
+    /// let wide: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    /// assert_eq!(wide.kron_right_identity(3), wide.kronecker(&Matrix::identity(3)));
+    /// ```
+    pub fn kron_right_identity(&self, n: usize) -> Matrix<T>
+    where
+        T: Zero + Clone,
+    {
+        let mut result: Matrix<T> = Matrix::zero(n * self.rows, n * self.cols);
+        for (i, value) in self.data.iter().enumerate() {
+            let (row, col) = (i / self.cols, i % self.cols);
+            for k in 0..n {
+                result.data[col * n + k + (row * n + k) * result.cols] = value.clone();
+            }
+        }
+        result
+    }
+
+    /// Try to compute the Moore-Penrose pseudo-inverse `(A^T * A)^-1 * A^T` of a matrix
+    /// with full column rank, as used for least-squares fitting.  
+    /// Returns `None` if `A^T * A` is singular.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::from_iter(3, 2, vec![1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+    /// let pinv = mat.pseudo_inverse().unwrap();
+    /// assert_eq!((pinv.rows(), pinv.cols()), (2, 3));
+    ///
+    /// let ident: Matrix<f64> = Matrix::identity(2);
+    /// for (value, expected) in (&pinv * &mat).iter().zip(ident.iter()) {
+    ///     assert!((value - expected).abs() < 1e-12);
+    /// }
+    ///
+    /// let deficient: Matrix<f64> = Matrix::from_iter(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+    /// assert!(deficient.pseudo_inverse().is_none());
+    /// ```
+    pub fn pseudo_inverse(&self) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        let inverse = self.transpose_mul(self)?.inverse()?;
+
+        // Cell (i, j) is the dot product of row i of the inverse and row j of A
+        let n = self.cols;
+        Some(Matrix::from_fn(n, self.rows, |i, j| {
+            (0..n).fold(T::zero(), |acc, k| {
+                acc + inverse.data[k + i * n] * self.data[k + j * n]
+            })
+        }))
+    }
+
+    /// Try to raise a *N*x*N* Matrix to the power `exp`, by repeated squaring.  
+    /// The power `0` is the identity matrix.  
+    /// Returns `None` if the matrix is not square, see `checked_pow` for the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let fib: Matrix<u64> = Matrix::new([[1, 1], [1, 0]]);
+    ///
+    /// assert_eq!(fib.pow(10), Some(Matrix::new([[89, 55], [55, 34]])));
+    /// assert_eq!(fib.pow(0), Some(Matrix::identity(2)));
+    /// assert!(Matrix::<u64>::zero(2, 3).pow(2).is_none());
+    /// ```
+    pub fn pow(&self, exp: u32) -> Option<Matrix<T>>
+    where
+        T: Add<Output = T> + Zero + One + Clone,
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+    {
+        self.checked_pow(exp).ok()
+    }
+
+    /// Raise a *N*x*N* Matrix to the power `exp`, by repeated squaring.  
+    /// The power `0` is the identity matrix.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::NotSquare` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, MatrixError};
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[2, 1], [0, 3]]);
+    /// assert_eq!(mat.checked_pow(3), Ok(&(&mat * &mat) * &mat));
+    ///
+    /// assert_eq!(
+    ///     Matrix::<i32>::zero(2, 3).checked_pow(2),
+    ///     Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    /// );
+    /// ```
+    pub fn checked_pow(&self, mut exp: u32) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Add<Output = T> + Zero + One + Clone,
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+    {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let mut result: Matrix<T> = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = &result * &base;
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Compute a rank-revealing QR decomposition with column pivoting, `A * P = Q * R`,
+    /// using Householder reflections.  
+    /// At each step, the remaining column with the largest norm is moved forward,
+    /// so the magnitudes on the diagonal of `R` decrease and the numerical rank of the matrix
+    /// is the number of diagonal cells above a tolerance.  
+    /// Returns the *rows*x*rows* orthogonal `Q`, the upper-triangular *rows*x*cols* `R`,
+    /// and the permutation `P` as a list of columns: column `j` of `A * P` is column `perm[j]` of `A`.  
+    /// Returns `None` if a cell is not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// // The last column is the sum of the first two: rank 2
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 9.0],
+    ///     [7.0, 8.0, 15.0],
+    ///     [1.0, 0.0, 1.0],
+    /// ]);
+    /// let (q, r, perm) = mat.qr_pivoted().unwrap();
+    ///
+    /// let mut sorted = perm.clone();
+    /// sorted.sort();
+    /// assert_eq!(sorted, vec![0, 1, 2]);
+    ///
+    /// let diagonal = r.diagonal();
+    /// assert!(diagonal[1].abs() > 1e-6);
+    /// assert!(diagonal[2].abs() < 1e-10);
+    ///
+    /// let product = &q * &r;
+    /// for row in 0..4 {
+    ///     for col in 0..3 {
+    ///         let expected = mat.get(row, perm[col]).unwrap();
+    ///         assert!((product.get(row, col).unwrap() - expected).abs() < 1e-10);
+    ///     }
+    /// }
+    /// ```
+    pub fn qr_pivoted(&self) -> Option<(Matrix<T>, Matrix<T>, Vec<usize>)>
+    where
+        T: Float,
+    {
+        if self.data.iter().any(|value| !value.is_finite()) {
+            return None;
+        }
+
+        let (m, n) = (self.rows, self.cols);
+        let mut q: Matrix<T> = Matrix::identity(m);
+        let mut r = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..m.min(n) {
+            // Move the remaining column with the largest norm to column k
+            let norm = |r: &Matrix<T>, j: usize| {
+                (k..m).fold(T::zero(), |sum, i| {
+                    sum + r.data[j + i * n] * r.data[j + i * n]
+                })
+            };
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    norm(&r, a)
+                        .partial_cmp(&norm(&r, b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            r.swap_cols(k, pivot);
+            perm.swap(k, pivot);
+
+            // Householder vector `v` reflecting column k onto the diagonal
+            let x0 = r.data[k + k * n];
+            let alpha = if x0 > T::zero() {
+                -norm(&r, k).sqrt()
+            } else {
+                norm(&r, k).sqrt()
+            };
+            let mut v: Vec<T> = (k..m).map(|i| r.data[k + i * n]).collect();
+            v[0] = v[0] - alpha;
+            let v_norm = v.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt();
+            if v_norm.is_zero() {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x = *x / v_norm);
+            let two = T::one() + T::one();
+
+            // R = H * R
+            for j in k..n {
+                let dot = (k..m).fold(T::zero(), |sum, i| sum + v[i - k] * r.data[j + i * n]);
+                for i in k..m {
+                    r.data[j + i * n] = r.data[j + i * n] - two * v[i - k] * dot;
+                }
+            }
+            for i in k + 1..m {
+                r.data[k + i * n] = T::zero();
+            }
+
+            // Q = Q * H
+            for i in 0..m {
+                let dot = (k..m).fold(T::zero(), |sum, j| sum + q.data[j + i * m] * v[j - k]);
+                for j in k..m {
+                    q.data[j + i * m] = q.data[j + i * m] - two * dot * v[j - k];
+                }
+            }
+        }
+
+        Some((q, r, perm))
+    }
+
+    /// Apply the rank-one update `A += alpha * u * v^T` in place,
+    /// without constructing the outer product.  
+    /// Returns `false` (and leaves the matrix untouched) if `u` does not have `rows` values
+    /// or `v` does not have `cols` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(2, 3, 0..);
+    /// let (u, v) = ([1, 2], [3, 0, -1]);
+    ///
+    /// let outer = Matrix::from_iter(2, 3, u.iter().flat_map(|a| v.iter().map(move |b| 2 * a * b)));
+    /// let expected = &mat + &outer;
+    ///
+    /// assert!(mat.rank_one_update(2, &u, &v));
+    /// assert_eq!(mat, expected);
+    ///
+    /// assert!(!mat.rank_one_update(2, &v, &u));
+    /// ```
+    pub fn rank_one_update(&mut self, alpha: T, u: &[T], v: &[T]) -> bool
+    where
+        T: Mul<Output = T> + AddAssign + Clone,
+    {
+        if u.len() != self.rows || v.len() != self.cols {
+            return false;
+        }
+
+        for (row, a) in u.iter().enumerate() {
+            let scaled = alpha.clone() * a.clone();
+            for (col, b) in v.iter().enumerate() {
+                self.data[col + row * self.cols] += scaled.clone() * b.clone();
+            }
+        }
+        true
+    }
+
+    /// Solve `L * X = B` by forward substitution, where `L` is the lower triangle of this *N*x*N* Matrix
+    /// (cells above the diagonal are ignored).  
+    /// `b` can have any number of columns, they are all solved in the same pass.  
+    /// Returns `None` if the matrix is not square, `b` does not have *N* rows,
+    /// or a diagonal cell is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let l: Matrix<f64> = Matrix::new([
+    ///     [2.0, 0.0, 0.0],
+    ///     [1.0, 1.0, 0.0],
+    ///     [3.0, -1.0, 4.0],
+    /// ]);
+    /// let b: Matrix<f64> = Matrix::from_iter(3, 2, vec![2.0, 4.0, 3.0, 1.0, 10.0, 2.0]);
+    ///
+    /// let x = l.solve_lower_triangular(&b).unwrap();
+    /// assert_eq!(x.cols(), 2);
+    /// assert_eq!(&l * &x, b);
+    ///
+    /// for col in 0..2 {
+    ///     let b_col = Matrix::from_iter(3, 1, b.get_col(col).unwrap().cloned());
+    ///     let x_col = l.solve_lower_triangular(&b_col).unwrap();
+    ///     assert!(x_col.iter().zip(x.get_col(col).unwrap()).all(|(a, b)| a == b));
+    /// }
+    /// ```
+    pub fn solve_lower_triangular(&self, b: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Clone + Zero + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.solve_triangular(b, 0..self.rows)
+    }
+
+    /// Solve `U * X = B` by back substitution, where `U` is the upper triangle of this *N*x*N* Matrix
+    /// (cells below the diagonal are ignored).  
+    /// `b` can have any number of columns, they are all solved in the same pass.  
+    /// Returns `None` if the matrix is not square, `b` does not have *N* rows,
+    /// or a diagonal cell is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let u: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, -1.0],
+    ///     [0.0, 2.0, 1.0],
+    ///     [0.0, 0.0, 4.0],
+    /// ]);
+    /// let b: Matrix<f64> = Matrix::from_iter(3, 2, vec![2.0, 1.0, 5.0, 2.0, 4.0, 8.0]);
+    ///
+    /// let x = u.solve_upper_triangular(&b).unwrap();
+    /// assert_eq!(&u * &x, b);
+    ///
+    /// assert!(Matrix::<f64>::zero(3, 3).solve_upper_triangular(&b).is_none());
+    /// ```
+    pub fn solve_upper_triangular(&self, b: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Clone + Zero + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.solve_triangular(b, (0..self.rows).rev())
+    }
+
+    // Substitution visiting the rows in the given order,
+    // each row only depends on the rows visited before it.
+    fn solve_triangular(
+        &self,
+        b: &Matrix<T>,
+        order: impl Iterator<Item = usize>,
+    ) -> Option<Matrix<T>>
+    where
+        T: Clone + Zero + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        if self.rows != self.cols || b.rows != self.rows {
+            return None;
+        }
+
+        let mut x = b.clone();
+        let mut solved: Vec<usize> = Vec::with_capacity(self.rows);
+        for i in order {
+            for &j in &solved {
+                let factor = self.data[j + i * self.cols].clone();
+                for k in 0..x.cols {
+                    let value = x.data[k + i * x.cols].clone()
+                        - factor.clone() * x.data[k + j * x.cols].clone();
+                    x.data[k + i * x.cols] = value;
+                }
+            }
+
+            let div = self.data[i + i * self.cols].clone();
+            if div.is_zero() {
+                return None;
+            }
+            for k in 0..x.cols {
+                let value = x.data[k + i * x.cols].clone() / div.clone();
+                x.data[k + i * x.cols] = value;
+            }
+            solved.push(i);
+        }
+        Some(x)
+    }
+
+    /// Iteratively solve `A * X = B` with the Jacobi method, starting from `X = 0`.  
+    /// Iterates until the Frobenius norm of the residual `B - A * X` falls below `tol`,
+    /// or `iterations` iterations have been done, and returns the last estimate.
+    /// Convergence is guaranteed for strictly diagonally dominant matrices.  
+    /// Returns `None` if the matrix is not square, `b` does not have *N* rows,
+    /// or a diagonal cell is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new([
+    ///     [10.0, -1.0, 2.0],
+    ///     [-1.0, 11.0, -1.0],
+    ///     [2.0, -1.0, 10.0],
+    /// ]);
+    /// let b: Matrix<f64> = Matrix::from_iter(3, 1, vec![14.0, 18.0, 30.0]);
+    ///
+    /// let x = a.jacobi(&b, 100, 1e-10).unwrap();
+    /// for (value, expected) in x.iter().zip(&[1.0, 2.0, 3.0]) {
+    ///     assert!((value - expected).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn jacobi(&self, b: &Matrix<T>, iterations: usize, tol: T) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        self.iterative_solve(b, iterations, tol, false)
+    }
+
+    /// Iteratively solve `A * X = B` with the Gauss-Seidel method, starting from `X = 0`.  
+    /// Unlike `jacobi`, updated cells are used as soon as they are computed,
+    /// which usually converges faster.
+    /// Iterates until the Frobenius norm of the residual `B - A * X` falls below `tol`,
+    /// or `iterations` iterations have been done, and returns the last estimate.  
+    /// Returns `None` if the matrix is not square, `b` does not have *N* rows,
+    /// or a diagonal cell is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new([
+    ///     [10.0, -1.0, 2.0],
+    ///     [-1.0, 11.0, -1.0],
+    ///     [2.0, -1.0, 10.0],
+    /// ]);
+    /// let b: Matrix<f64> = Matrix::from_iter(3, 1, vec![14.0, 18.0, 30.0]);
+    ///
+    /// let x = a.gauss_seidel(&b, 100, 1e-10).unwrap();
+    /// for (value, expected) in x.iter().zip(&[1.0, 2.0, 3.0]) {
+    ///     assert!((value - expected).abs() < 1e-9);
+    /// }
+    ///
+    /// assert!(Matrix::<f64>::zero(3, 3).gauss_seidel(&b, 100, 1e-10).is_none());
+    /// ```
+    pub fn gauss_seidel(&self, b: &Matrix<T>, iterations: usize, tol: T) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        self.iterative_solve(b, iterations, tol, true)
+    }
+
+    /// Constructs a new *N*x*N* tridiagonal Matrix<T> from its three diagonals:
+    /// `sub` below the main diagonal, `diag` on it and `sup` above it.  
+    /// Returns `None` if `diag` is empty or `sub` and `sup` do not have *N - 1* values.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::tridiagonal(&[1, 2], &[3, 4, 5], &[6, 7]).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new([
+    ///     [3, 6, 0],
+    ///     [1, 4, 7],
+    ///     [0, 2, 5],
+    /// ]));
+    ///
+    /// assert!(Matrix::tridiagonal(&[1], &[3, 4, 5], &[6, 7]).is_none());
+    /// ```
+    pub fn tridiagonal(sub: &[T], diag: &[T], sup: &[T]) -> Option<Matrix<T>>
+    where
+        T: Zero + Clone,
+    {
+        let n = diag.len();
+        if n == 0 || sub.len() != n - 1 || sup.len() != n - 1 {
+            return None;
+        }
+
+        let mut result = Matrix::zero(n, n);
+        for i in 0..n {
+            result.data[i + i * n] = diag[i].clone();
+            if i + 1 < n {
+                result.data[i + (i + 1) * n] = sub[i].clone();
+                result.data[i + 1 + i * n] = sup[i].clone();
+            }
+        }
+        Some(result)
+    }
+
+    /// Solve `A * X = B` for a tridiagonal *N*x*N* Matrix with the `O(n)` Thomas algorithm.  
+    /// The algorithm does not pivot, it is stable for diagonally dominant matrices.  
+    /// Returns `None` if the matrix is not square or not tridiagonal,
+    /// `b` does not have *N* rows, or a zero pivot is met.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::tridiagonal(&[1.0, 1.0, 1.0], &[4.0, 4.0, 4.0, 4.0], &[1.0, 1.0, 1.0]).unwrap();
+    /// let b: Matrix<f64> = Matrix::from_iter(4, 1, vec![5.0, 6.0, 6.0, 5.0]);
+    ///
+    /// let x = a.solve_tridiagonal(&b).unwrap();
+    /// let expected = &a.inverse().unwrap() * &b;
+    /// for (value, expected) in x.iter().zip(expected.iter()) {
+    ///     assert!((value - expected).abs() < 1e-12);
+    /// }
+    ///
+    /// assert!(Matrix::<f64>::identity(4).solve_tridiagonal(&b).is_some());
+    /// let dense: Matrix<f64> = Matrix::from_iter(4, 4, (1..).map(f64::from));
+    /// assert!(dense.solve_tridiagonal(&b).is_none());
+    /// ```
+    pub fn solve_tridiagonal(&self, b: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        let n = self.rows;
+        if n != self.cols || b.rows != n {
+            return None;
+        }
+        for row in 0..n {
+            for col in 0..n {
+                if (row as isize - col as isize).abs() > 1 && !self.data[col + row * n].is_zero() {
+                    return None;
+                }
+            }
+        }
+
+        let sub = |i: usize| self.data[i - 1 + i * n];
+        let diag = |i: usize| self.data[i + i * n];
+        let sup = |i: usize| self.data[i + 1 + i * n];
+
+        let mut x = b.clone();
+        // Modified super-diagonal of the forward sweep
+        let mut c = vec![T::zero(); n];
+        for k in 0..b.cols {
+            for i in 0..n {
+                let mut pivot = diag(i);
+                let mut value = b.data[k + i * b.cols];
+                if i > 0 {
+                    pivot = pivot - sub(i) * c[i - 1];
+                    value = value - sub(i) * x.data[k + (i - 1) * b.cols];
+                }
+                if pivot.is_zero() {
+                    return None;
+                }
+                if i + 1 < n {
+                    c[i] = sup(i) / pivot;
+                }
+                x.data[k + i * b.cols] = value / pivot;
+            }
+
+            for i in (0..n - 1).rev() {
+                let next = x.data[k + (i + 1) * b.cols];
+                x.data[k + i * b.cols] = x.data[k + i * b.cols] - c[i] * next;
+            }
+        }
+        Some(x)
+    }
+
+    /// Constructs the 3x3 skew-symmetric cross-product Matrix<T> of the 3-vector `v`,
+    /// such that multiplying it by `w` computes the cross product `v × w`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let x = Matrix::cross_matrix(&[1, 0, 0]);
+    /// let y: Matrix<i32> = Matrix::from_iter(3, 1, vec![0, 1, 0]);
+    ///
+    /// assert_eq!(&x * &y, Matrix::from_iter(3, 1, vec![0, 0, 1]));
+    /// assert_eq!(x, -x.transpose());
+    /// ```
+    pub fn cross_matrix(v: &[T; 3]) -> Matrix<T>
+    where
+        T: Neg<Output = T> + Zero + Clone,
+    {
+        let [x, y, z] = v.clone();
+        Matrix::new([
+            [T::zero(), -z.clone(), y.clone()],
+            [z, T::zero(), -x.clone()],
+            [-y, x, T::zero()],
+        ])
+    }
+
+    /// Constructs the *N*x*N* companion Matrix<T> of the polynomial
+    /// `a0 + a1 * x + ... + aN * x^N`, given its coefficients `[a0, a1, ..., aN]`.  
+    /// Its eigenvalues are the roots of the polynomial.
+    /// The polynomial is made monic by dividing by `aN`: the sub-diagonal is set to one,
+    /// and the last column to `-a0 / aN, ..., -aN-1 / aN`.  
+    /// Returns `None` if there are less than two coefficients or the leading one is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+    /// let mat: Matrix<f64> = Matrix::companion(&[-6.0, 11.0, -6.0, 1.0]).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new([
+    ///     [0.0, 0.0, 6.0],
+    ///     [1.0, 0.0, -11.0],
+    ///     [0.0, 1.0, 6.0],
+    /// ]));
+    ///
+    /// // The determinant is the product of the roots
+    /// assert_eq!(mat.determinant(), Some(6.0));
+    ///
+    /// assert!(Matrix::<f64>::companion(&[1.0, 2.0, 0.0]).is_none());
+    /// assert!(Matrix::<f64>::companion(&[1.0]).is_none());
+    /// ```
+    pub fn companion(coeffs: &[T]) -> Option<Matrix<T>>
+    where
+        T: Zero + One + Neg<Output = T> + Div<Output = T> + Clone,
+    {
+        let (lead, coeffs) = coeffs.split_last()?;
+        if coeffs.is_empty() || lead.is_zero() {
+            return None;
+        }
+
+        let n = coeffs.len();
+        let mut result = Matrix::zero(n, n);
+        for i in 1..n {
+            result.data[i - 1 + i * n] = T::one();
+        }
+        for (i, coeff) in coeffs.iter().enumerate() {
+            result.data[n - 1 + i * n] = -(coeff.clone() / lead.clone());
+        }
+        Some(result)
+    }
+
+    /// Construct the row echelon form of the matrix, with forward elimination only.  
+    /// In each column, the row with the largest absolute value is chosen as pivot (partial pivoting).
+    /// Pivots are not normalized to one and cells above them are not eliminated,
+    /// which makes it cheaper than the reduced row echelon form.  
+    /// The rank of the matrix is the number of non-zero rows of the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, 1.0],
+    ///     [4.0, 2.0, 0.0],
+    ///     [2.0, 1.0, 3.0],
+    /// ]);
+    /// let echelon = mat.ref_form();
+    ///
+    /// // Upper-triangular for a full-rank square matrix
+    /// for row in 1..3 {
+    ///     for col in 0..row {
+    ///         assert_eq!(echelon.get(row, col).unwrap(), 0.0);
+    ///     }
+    /// }
+    ///
+    /// let deficient: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, 3.0],
+    ///     [2.0, 4.0, 6.0],
+    ///     [1.0, 0.0, 1.0],
+    /// ]);
+    /// let echelon = deficient.ref_form();
+    /// let rank = (0..3).filter(|&row| echelon.get_row(row).unwrap().any(|n| *n != 0.0)).count();
+    /// assert_eq!(rank, 2);
+    /// ```
+    pub fn ref_form(&self) -> Matrix<T>
+    where
+        T: Float,
+    {
+        let mut matrix = self.clone();
+        let cols = self.cols;
+
+        let mut lead = 0;
+        for r in 0..matrix.rows {
+            let pivot = loop {
+                if lead >= cols {
+                    return matrix;
+                }
+
+                let pivot = (r..matrix.rows)
+                    .max_by(|&a, &b| {
+                        let a = matrix.data[lead + a * cols].abs();
+                        let b = matrix.data[lead + b * cols].abs();
+                        a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .unwrap();
+                if matrix.data[lead + pivot * cols].is_zero() {
+                    lead += 1;
+                } else {
+                    break pivot;
+                }
+            };
+
+            matrix.swap_rows(pivot, r);
+            let div = matrix.data[lead + r * cols];
+            for i in r + 1..matrix.rows {
+                let factor = matrix.data[lead + i * cols] / div;
+                matrix.data[lead + i * cols] = T::zero();
+                for j in lead + 1..cols {
+                    matrix.data[j + i * cols] =
+                        matrix.data[j + i * cols] - factor * matrix.data[j + r * cols];
+                }
+            }
+            lead += 1;
+        }
+        matrix
+    }
+
+    fn iterative_solve(
+        &self,
+        b: &Matrix<T>,
+        iterations: usize,
+        tol: T,
+        in_place: bool,
+    ) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        let n = self.rows;
+        if n != self.cols || b.rows != n || (0..n).any(|i| self.data[i + i * n].is_zero()) {
+            return None;
+        }
+
+        let mut x = Matrix::zero(n, b.cols);
+        for _ in 0..iterations {
+            if self.residual_norm(&x, b) < tol {
+                break;
+            }
+
+            let previous = if in_place { None } else { Some(x.clone()) };
+            for i in 0..n {
+                for k in 0..b.cols {
+                    let current = previous.as_ref().unwrap_or(&x);
+                    let mut sum = b.data[k + i * b.cols];
+                    for j in (0..n).filter(|&j| j != i) {
+                        sum = sum - self.data[j + i * n] * current.data[k + j * b.cols];
+                    }
+                    x.data[k + i * b.cols] = sum / self.data[i + i * n];
+                }
+            }
+        }
+        Some(x)
+    }
+
+    // Frobenius norm of `b - self * x`
+    fn residual_norm(&self, x: &Matrix<T>, b: &Matrix<T>) -> T
+    where
+        T: Float,
+    {
+        let mut sum = T::zero();
+        for i in 0..self.rows {
+            for k in 0..b.cols {
+                let mut residual = b.data[k + i * b.cols];
+                for j in 0..self.cols {
+                    residual = residual - self.data[j + i * self.cols] * x.data[k + j * x.cols];
+                }
+                sum = sum + residual * residual;
+            }
+        }
+        sum.sqrt()
+    }
+
+    fn norm_1(&self) -> T
+    where
+        T: Float,
+    {
+        (0..self.cols)
+            .map(|col| {
+                self.get_col(col)
+                    .unwrap()
+                    .fold(T::zero(), |acc, n| acc + n.abs())
+            })
+            .fold(T::zero(), T::max)
+    }
+}
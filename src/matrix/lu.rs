@@ -0,0 +1,438 @@
+use super::{Matrix, MatrixError};
+use num_traits::{Float, One};
+use std::ops::{Mul, Neg};
+
+/// LU decomposition with partial pivoting of a *N*x*N* Matrix `A`,
+/// such that `P * A = L * U`.
+///
+/// `L` is lower triangular with ones on its diagonal, `U` is upper triangular,
+/// and `P` is the row permutation chosen while pivoting.  
+/// Constructed with `Matrix::lu`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LuDecomposition<T> {
+    l: Matrix<T>,
+    u: Matrix<T>,
+    perm: Vec<usize>,
+    swaps: usize,
+}
+
+impl<T> LuDecomposition<T> {
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Returns the row permutation: row `i` of `P * A` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns the determinant of the decomposed matrix,
+    /// read from the diagonal of `U` without any further elimination.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [0.0, 2.0, 1.0],
+    ///     [1.0, 1.0, 0.0],
+    ///     [3.0, 0.0, 1.0],
+    /// ]);
+    /// let lu = mat.lu().unwrap();
+    ///
+    /// assert!((lu.determinant() - mat.determinant().unwrap()).abs() < 1e-12);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Float,
+    {
+        Matrix::det_from_lu(&self.l, &self.u, self.sign())
+    }
+
+    // Solve `A * X = B` by permuting `B`, then forward and back substitution
+    fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        let mut b = b.clone();
+        b.apply_row_permutation_inplace(&self.perm);
+
+        let y = self.l.solve_lower_triangular(&b)?;
+        self.u.solve_upper_triangular(&y)
+    }
+
+    /// Returns the number of row swaps done while pivoting.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [0.0, 0.0, 1.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [1.0, 0.0, 0.0],
+    /// ]);
+    ///
+    /// assert_eq!(mat.lu().unwrap().swap_count(), 1);
+    /// assert_eq!(Matrix::<f64>::identity(3).lu().unwrap().swap_count(), 0);
+    /// ```
+    pub fn swap_count(&self) -> usize {
+        self.swaps
+    }
+
+    /// Returns the sign of the permutation `P`, `(-1)^swap_count()`:
+    /// one for an even number of row swaps, minus one for an odd number.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [0.0, 2.0, 1.0],
+    ///     [1.0, 1.0, 0.0],
+    ///     [3.0, 0.0, 1.0],
+    /// ]);
+    /// let lu = mat.lu().unwrap();
+    ///
+    /// assert_eq!(lu.swap_count(), 2);
+    /// assert_eq!(lu.sign(), (-1.0f64).powi(lu.swap_count() as i32));
+    ///
+    /// let swapped: Matrix<f64> = Matrix::new([[0.0, 1.0], [1.0, 0.0]]);
+    /// assert_eq!(swapped.lu().unwrap().sign(), -1.0);
+    /// ```
+    pub fn sign(&self) -> T
+    where
+        T: One + Neg<Output = T>,
+    {
+        if self.swaps % 2 == 0 {
+            T::one()
+        } else {
+            -T::one()
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Compute the LU decomposition with partial pivoting of a *N*x*N* Matrix.  
+    /// In each column, the row with the largest absolute value is chosen as pivot.
+    /// Singular matrices can be decomposed too, `U` then has a zero on its diagonal.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, 1.0],
+    ///     [4.0, 2.0, 0.0],
+    ///     [2.0, 1.0, 3.0],
+    /// ]);
+    /// let lu = mat.lu().unwrap();
+    ///
+    /// let mut permuted = mat.clone();
+    /// permuted.apply_row_permutation_inplace(lu.permutation());
+    ///
+    /// let product = lu.l() * lu.u();
+    /// for (value, expected) in product.iter().zip(permuted.iter()) {
+    ///     assert!((value - expected).abs() < 1e-12);
+    /// }
+    ///
+    /// assert!(Matrix::<f64>::zero(2, 3).lu().is_none());
+    /// ```
+    pub fn lu(&self) -> Option<LuDecomposition<T>>
+    where
+        T: Float,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut l: Matrix<T> = Matrix::identity(n);
+        let mut u = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    let a = u.data[k + a * n].abs();
+                    let b = u.data[k + b * n].abs();
+                    a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            if pivot != k {
+                u.swap_rows(pivot, k);
+                perm.swap(pivot, k);
+                for j in 0..k {
+                    l.data.swap(j + pivot * n, j + k * n);
+                }
+                swaps += 1;
+            }
+
+            let div = u.data[k + k * n];
+            if div.is_zero() {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = u.data[k + i * n] / div;
+                l.data[k + i * n] = factor;
+                u.data[k + i * n] = T::zero();
+                for j in k + 1..n {
+                    u.data[j + i * n] = u.data[j + i * n] - factor * u.data[j + k * n];
+                }
+            }
+        }
+
+        Some(LuDecomposition { l, u, perm, swaps })
+    }
+
+    /// Take a *N*x*N* Matrix and construct the inverse of it, choosing the largest pivot
+    /// of each column (see `lu`).  
+    /// This is more accurate than `inverse`, which takes the first non-zero pivot.  
+    /// Returns `None` if the matrix is not square or is singular.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// fn reconstruction_error(mat: &Matrix<f64>, inverse: &Matrix<f64>) -> f64 {
+    ///     let product = mat * inverse;
+    ///     let ident: Matrix<f64> = Matrix::identity(mat.rows());
+    ///     product.iter().zip(ident.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max)
+    /// }
+    ///
+    /// // A tiny leading pivot ruins the elimination without pivoting
+    /// let mat: Matrix<f64> = Matrix::new([[1e-20, 1.0], [1.0, 1.0]]);
+    /// let stable = reconstruction_error(&mat, &mat.inverse_stable().unwrap());
+    /// let naive = reconstruction_error(&mat, &mat.inverse().unwrap());
+    /// assert!(stable < 1e-12);
+    /// assert!(naive > 0.5);
+    ///
+    /// let hilbert = Matrix::from_fn(5, 5, |row, col| 1.0 / (row + col + 1) as f64);
+    /// assert!(reconstruction_error(&hilbert, &hilbert.inverse_stable().unwrap()) < 1e-9);
+    ///
+    /// let singular: Matrix<f64> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!(singular.inverse_stable().is_none());
+    /// ```
+    pub fn inverse_stable(&self) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        self.lu()?.solve(&Matrix::identity(self.rows))
+    }
+
+    /// Solve `A * X = B` for float matrices, choosing the method from the shape of `A`:
+    /// - square systems are solved exactly, using `lu`,
+    /// - overdetermined systems (more rows than columns) get the least-squares solution,
+    ///   solving the normal equations `A^T * A * X = A^T * B` with `lu`.
+    ///
+    /// A pivot of the elimination counts as zero if its magnitude is at most
+    /// `N * epsilon` times the largest cell of the eliminated *N*x*N* Matrix.
+    ///
+    /// # Errors
+    /// Returns `MatrixError::DimensionMismatch` if `b` does not have as many rows as `A`.  
+    /// Returns `MatrixError::Underdetermined` if `A` has fewer rows than columns.  
+    /// Returns `MatrixError::Singular` if `A` is singular or does not have full column rank,
+    /// up to the pivot tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, MatrixError};
+    ///
+    /// let a: Matrix<f64> = Matrix::new([[2.0, 1.0], [1.0, 3.0]]);
+    /// let b: Matrix<f64> = Matrix::from_iter(2, 1, vec![3.0, 5.0]);
+    /// let x = a.solve_or_lstsq(&b).unwrap();
+    /// assert!((x.get(0, 0).unwrap() - 0.8).abs() < 1e-12);
+    /// assert!((x.get(1, 0).unwrap() - 1.4).abs() < 1e-12);
+    ///
+    /// // Fit y = c0 + c1 * t through (0, 1), (1, 2), (2, 4): c0 = 5 / 6, c1 = 3 / 2
+    /// let a: Matrix<f64> = Matrix::new([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+    /// let b: Matrix<f64> = Matrix::from_iter(3, 1, vec![1.0, 2.0, 4.0]);
+    /// let c = a.solve_or_lstsq(&b).unwrap();
+    /// assert!((c.get(0, 0).unwrap() - 5.0 / 6.0).abs() < 1e-12);
+    /// assert!((c.get(1, 0).unwrap() - 1.5).abs() < 1e-12);
+    ///
+    /// let singular: Matrix<f64> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.solve_or_lstsq(&Matrix::zero(2, 1)), Err(MatrixError::Singular));
+    ///
+    /// // Singular up to rounding, elimination leaves a pivot of about 1e-16
+    /// let nearly: Matrix<f64> = Matrix::new([[1.0, 2.0], [3.0, 6.0 + 1e-15]]);
+    /// assert_eq!(nearly.solve_or_lstsq(&Matrix::zero(2, 1)), Err(MatrixError::Singular));
+    ///
+    /// let collinear: Matrix<f64> = Matrix::new([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    /// assert_eq!(collinear.solve_or_lstsq(&Matrix::zero(3, 1)), Err(MatrixError::Singular));
+    ///
+    /// let wide: Matrix<f64> = Matrix::zero(2, 3);
+    /// assert_eq!(
+    ///     wide.solve_or_lstsq(&Matrix::zero(2, 1)),
+    ///     Err(MatrixError::Underdetermined { rows: 2, cols: 3 })
+    /// );
+    /// ```
+    pub fn solve_or_lstsq(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Float,
+    {
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.rows, self.cols),
+                right: (b.rows, b.cols),
+            });
+        }
+        if self.rows < self.cols {
+            return Err(MatrixError::Underdetermined {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        if self.rows == self.cols {
+            self.solve_checked(b)
+        } else {
+            // Normal equations `A^T * A * X = A^T * B`
+            let gram = self.transpose_mul(self).unwrap();
+            let rhs = self.transpose_mul(b).unwrap();
+            gram.solve_checked(&rhs)
+        }
+    }
+
+    // Solve a square system by `lu`, treating pivots that are small relative to the
+    // largest cell as zero
+    fn solve_checked(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Float,
+    {
+        let lu = self.lu().unwrap();
+        let largest = self
+            .data
+            .iter()
+            .fold(T::zero(), |max, value| max.max(value.abs()));
+        let tolerance = largest * T::epsilon() * T::from(self.rows).unwrap();
+        if lu.u.iter_diagonal().any(|pivot| pivot.abs() <= tolerance) {
+            return Err(MatrixError::Singular);
+        }
+        lu.solve(b).ok_or(MatrixError::Singular)
+    }
+
+    /// Check whether a *N*x*N* Matrix is singular, up to the tolerance `eps`.  
+    /// Runs a single pass of partially pivoted elimination (see `lu`) and reports
+    /// whether any pivot has a magnitude below `eps`.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let singular: Matrix<f64> = Matrix::new([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 9.0],
+    /// ]);
+    /// assert_eq!(singular.is_singular(1e-10), Some(true));
+    ///
+    /// let regular: Matrix<f64> = Matrix::new([[2.0, 1.0], [1.0, 3.0]]);
+    /// assert_eq!(regular.is_singular(1e-10), Some(false));
+    ///
+    /// assert!(Matrix::<f64>::zero(2, 3).is_singular(1e-10).is_none());
+    /// ```
+    pub fn is_singular(&self, eps: T) -> Option<bool>
+    where
+        T: Float,
+    {
+        let lu = self.lu()?;
+        let singular = lu.u.iter_diagonal().any(|pivot| pivot.abs() < eps);
+        Some(singular)
+    }
+
+    /// Check whether a float matrix is symmetric positive definite.
+    /// Runs a Cholesky factorization `A = L * L^T` and stops at the first pivot that is not
+    /// strictly positive.
+    /// Returns `false` if the matrix is not square or not exactly symmetric.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let spd: Matrix<f64> = Matrix::new([
+    ///     [4.0, 12.0, -16.0],
+    ///     [12.0, 37.0, -43.0],
+    ///     [-16.0, -43.0, 98.0],
+    /// ]);
+    /// assert!(spd.is_positive_definite());
+    ///
+    /// // Eigenvalues 3 and -1
+    /// let indefinite: Matrix<f64> = Matrix::new([[1.0, 2.0], [2.0, 1.0]]);
+    /// assert!(!indefinite.is_positive_definite());
+    ///
+    /// let not_symmetric: Matrix<f64> = Matrix::new([[2.0, 1.0], [0.0, 2.0]]);
+    /// assert!(!not_symmetric.is_positive_definite());
+    ///
+    /// // Semi-definite only
+    /// assert!(!Matrix::<f64>::zero(2, 2).is_positive_definite());
+    /// ```
+    pub fn is_positive_definite(&self) -> bool
+    where
+        T: Float,
+    {
+        let n = self.rows;
+        if self.cols != n {
+            return false;
+        }
+        for i in 0..n {
+            for j in i + 1..n {
+                if self.data[j + i * n] != self.data[i + j * n] {
+                    return false;
+                }
+            }
+        }
+
+        let mut l: Matrix<T> = Matrix::zero(n, n);
+        for j in 0..n {
+            let pivot = (0..j).fold(self.data[j + j * n], |acc, k| {
+                acc - l.data[k + j * n] * l.data[k + j * n]
+            });
+            if pivot <= T::zero() || pivot.is_nan() {
+                return false;
+            }
+            let pivot = pivot.sqrt();
+            l.data[j + j * n] = pivot;
+
+            for i in j + 1..n {
+                let value = (0..j).fold(self.data[j + i * n], |acc, k| {
+                    acc - l.data[k + i * n] * l.data[k + j * n]
+                });
+                l.data[j + i * n] = value / pivot;
+            }
+        }
+        true
+    }
+
+    /// Compute a determinant from an existing LU factorization `P * A = L * U`,
+    /// as `perm_sign` times the product of the diagonals of `L` and `U`.  
+    /// `perm_sign` is the sign of the permutation `P`: one for an even number of row swaps,
+    /// minus one for an odd number.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let l: Matrix<f64> = Matrix::new([[1.0, 0.0], [0.5, 1.0]]);
+    /// let u: Matrix<f64> = Matrix::new([[4.0, 2.0], [0.0, 3.0]]);
+    ///
+    /// assert_eq!(Matrix::det_from_lu(&l, &u, -1.0), -12.0);
+    /// ```
+    pub fn det_from_lu(l: &Matrix<T>, u: &Matrix<T>, perm_sign: T) -> T
+    where
+        T: Mul<Output = T> + One + Clone,
+    {
+        l.iter_diagonal()
+            .chain(u.iter_diagonal())
+            .fold(perm_sign, |acc, n| acc * n.clone())
+    }
+}
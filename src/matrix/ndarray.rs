@@ -0,0 +1,48 @@
+use super::Matrix;
+use ::ndarray::Array2;
+
+impl<T> Matrix<T> {
+    /// Construct an `ndarray::Array2<T>` with the same shape and cells.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 0..);
+    /// let arr = mat.to_ndarray();
+    ///
+    /// assert_eq!(arr.dim(), (2, 3));
+    /// assert_eq!(arr[[1, 0]], 3);
+    /// assert_eq!(Matrix::from_ndarray(&arr), mat);
+    /// ```
+    pub fn to_ndarray(&self) -> Array2<T>
+    where
+        T: Clone,
+    {
+        Array2::from_shape_vec((self.rows, self.cols), self.data.clone()).unwrap()
+    }
+
+    /// Constructs a new Matrix<T> from an `ndarray::Array2<T>`, whatever its memory layout.
+    ///
+    /// # Panics
+    /// Panics if the array has no rows or no columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::array;
+    /// use simple_matrix::Matrix;
+    ///
+    /// let arr = array![[1, 2, 3], [4, 5, 6]];
+    /// let mat = Matrix::from_ndarray(&arr.t().to_owned());
+    ///
+    /// assert_eq!(mat.rows(), 3);
+    /// assert_eq!(mat.cols(), 2);
+    /// assert_eq!(mat.get(0, 1).unwrap(), 4);
+    /// ```
+    pub fn from_ndarray(arr: &Array2<T>) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_iter(arr.nrows(), arr.ncols(), arr.iter().cloned())
+    }
+}
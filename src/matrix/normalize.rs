@@ -0,0 +1,233 @@
+use super::Matrix;
+use num_traits::Float;
+
+impl<T: Float> Matrix<T> {
+    /// Construct a new matrix by applying a softmax to every row, so every row sums to `1`.  
+    /// The row maximum is subtracted before exponentiating, so large values do not overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let logits: Matrix<f64> = Matrix::new([[1.0, 2.0, 3.0], [1000.0, 1000.0, -1000.0], [0.0; 3]]);
+    /// let probs = logits.softmax_rows();
+    ///
+    /// for row in probs.chunks(3) {
+    ///     assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    /// }
+    /// assert!(probs.iter().all(|p| !p.is_nan()));
+    /// assert_eq!(probs.get(1, 0).unwrap(), 0.5);
+    /// assert!(probs.get(0, 2).unwrap() > probs.get(0, 1).unwrap());
+    /// ```
+    pub fn softmax_rows(&self) -> Matrix<T> {
+        let mut data = self.data.clone();
+        for row in data.chunks_mut(self.cols) {
+            let max = row
+                .iter()
+                .fold(T::neg_infinity(), |max, &value| max.max(value));
+            let mut sum = T::zero();
+            for value in row.iter_mut() {
+                *value = (*value - max).exp();
+                sum = sum + *value;
+            }
+            row.iter_mut().for_each(|value| *value = *value / sum);
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// Construct a new matrix by applying a softmax to every column, so every column sums to `1`.  
+    /// See `softmax_rows` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let logits: Matrix<f64> = Matrix::from_iter(2, 3, vec![1.0, 500.0, 0.0, 1.0, -500.0, 2.0]);
+    /// let probs = logits.softmax_cols();
+    ///
+    /// assert_eq!(probs.get(0, 0).unwrap(), 0.5);
+    /// assert_eq!(probs.get(0, 1).unwrap(), 1.0);
+    /// assert_eq!(probs, logits.transpose().softmax_rows().transpose());
+    /// ```
+    pub fn softmax_cols(&self) -> Matrix<T> {
+        self.transpose().softmax_rows().transpose()
+    }
+
+    /// Construct a new matrix where every row is standardized to a mean of `0`
+    /// and a (population) standard deviation of `1`.  
+    /// Rows with zero variance are only centered.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 4, vec![1.0, 2.0, 3.0, 4.0, 5.0, 5.0, 5.0, 5.0]);
+    /// let standard = mat.standardize_rows();
+    ///
+    /// let row: Vec<f64> = standard.get_row(0).unwrap().cloned().collect();
+    /// assert!(row.iter().sum::<f64>().abs() < 1e-12);
+    /// assert!((row.iter().map(|n| n * n).sum::<f64>() / 4.0 - 1.0).abs() < 1e-12);
+    ///
+    /// assert!(standard.get_row(1).unwrap().all(|n| *n == 0.0));
+    /// ```
+    pub fn standardize_rows(&self) -> Matrix<T> {
+        let count = T::from(self.cols).unwrap();
+
+        let mut data = self.data.clone();
+        for row in data.chunks_mut(self.cols) {
+            let mean = row.iter().fold(T::zero(), |sum, &value| sum + value) / count;
+            let variance = row.iter().fold(T::zero(), |sum, &value| {
+                sum + (value - mean) * (value - mean)
+            }) / count;
+            let std_dev = if variance.is_zero() {
+                T::one()
+            } else {
+                variance.sqrt()
+            };
+            row.iter_mut()
+                .for_each(|value| *value = (*value - mean) / std_dev);
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// Construct a new matrix where every column is standardized to a mean of `0`
+    /// and a (population) standard deviation of `1`, e.g. to preprocess features.  
+    /// Columns with zero variance are only centered.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let features: Matrix<f64> = Matrix::from_iter(4, 2, vec![
+    ///     1.0, 10.0,
+    ///     2.0, 30.0,
+    ///     3.0, 20.0,
+    ///     6.0, 60.0,
+    /// ]);
+    /// let standard = features.standardize_cols();
+    ///
+    /// for col in 0..2 {
+    ///     let values: Vec<f64> = standard.get_col(col).unwrap().cloned().collect();
+    ///     let mean = values.iter().sum::<f64>() / 4.0;
+    ///     let variance = values.iter().map(|n| (n - mean) * (n - mean)).sum::<f64>() / 4.0;
+    ///     assert!(mean.abs() < 1e-12);
+    ///     assert!((variance.sqrt() - 1.0).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn standardize_cols(&self) -> Matrix<T> {
+        self.transpose().standardize_rows().transpose()
+    }
+
+    /// Construct a new matrix where every row is divided by its sum, so every row sums to `1`,
+    /// like the transition matrix of a Markov chain.  
+    /// Rows summing to zero are left as they are, so all-zero rows stay zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let counts: Matrix<f64> = Matrix::new([[1.0, 3.0, 0.0], [0.0; 3], [2.0, 2.0, 6.0]]);
+    /// let transitions = counts.to_row_stochastic();
+    ///
+    /// assert_eq!(transitions.get_row(0).unwrap().cloned().collect::<Vec<_>>(), vec![0.25, 0.75, 0.0]);
+    /// assert!(transitions.get_row(1).unwrap().all(|p| *p == 0.0));
+    /// assert!((transitions.row_sums()[2] - 1.0).abs() < 1e-12);
+    ///
+    /// let twice = transitions.to_row_stochastic();
+    /// for (a, b) in twice.iter().zip(transitions.iter()) {
+    ///     assert!((a - b).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn to_row_stochastic(&self) -> Matrix<T> {
+        let mut data = self.data.clone();
+        for row in data.chunks_mut(self.cols) {
+            let sum = row.iter().fold(T::zero(), |sum, &value| sum + value);
+            if !sum.is_zero() {
+                row.iter_mut().for_each(|value| *value = *value / sum);
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// Construct a new matrix where every column is divided by its sum, so every column sums to `1`.  
+    /// See `to_row_stochastic` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let counts: Matrix<f64> = Matrix::from_iter(2, 3, vec![1.0, 0.0, 5.0, 3.0, 0.0, 5.0]);
+    /// let transitions = counts.to_col_stochastic();
+    ///
+    /// assert_eq!(transitions, Matrix::from_iter(2, 3, vec![0.25, 0.0, 0.5, 0.75, 0.0, 0.5]));
+    /// assert_eq!(transitions.to_col_stochastic(), transitions);
+    /// ```
+    pub fn to_col_stochastic(&self) -> Matrix<T> {
+        self.transpose().to_row_stochastic().transpose()
+    }
+
+    /// Scale all cells down so the Frobenius norm of the matrix does not exceed `max_norm`,
+    /// in place, e.g. to clip gradients.  
+    /// Matrices whose norm is already at most `max_norm` are left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut large: Matrix<f64> = Matrix::from_iter(3, 3, (1..).map(f64::from));
+    /// large.clip_norm(2.0);
+    /// let norm = large.iter().map(|n| n * n).sum::<f64>().sqrt();
+    /// assert!((norm - 2.0).abs() < 1e-12);
+    /// assert!((large.get(0, 1).unwrap() / large.get(0, 0).unwrap() - 2.0).abs() < 1e-12);
+    ///
+    /// let mut small: Matrix<f64> = Matrix::new([[0.1, 0.2], [0.3, 0.4]]);
+    /// small.clip_norm(1.0);
+    /// assert_eq!(small, Matrix::new([[0.1, 0.2], [0.3, 0.4]]));
+    /// ```
+    pub fn clip_norm(&mut self, max_norm: T) {
+        let norm = self
+            .data
+            .iter()
+            .fold(T::zero(), |sum, &value| sum + value * value)
+            .sqrt();
+        if norm > max_norm {
+            let factor = max_norm / norm;
+            self.data
+                .iter_mut()
+                .for_each(|value| *value = *value * factor);
+        }
+    }
+
+    /// Construct a new matrix scaled down so its Frobenius norm does not exceed `max_norm`.  
+    /// See `clip_norm` for the in-place version.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![6.0, 8.0, 0.0, 0.0]);
+    ///
+    /// assert_eq!(mat.clipped_norm(5.0), Matrix::from_iter(2, 2, vec![3.0, 4.0, 0.0, 0.0]));
+    /// assert_eq!(mat.clipped_norm(20.0), mat);
+    /// ```
+    pub fn clipped_norm(&self, max_norm: T) -> Matrix<T> {
+        let mut result = self.clone();
+        result.clip_norm(max_norm);
+        result
+    }
+}
@@ -0,0 +1,35 @@
+use super::Matrix;
+use ::rayon::prelude::*;
+
+impl<T: Send> Matrix<T> {
+    /// Constructs a new, non-empty Matrix<T> like `from_fn`, but computes the cells in parallel.  
+    /// The cells are placed row by row, so the result is the same as with `from_fn` as long as
+    /// `f` only depends on the coordinates.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat = Matrix::from_fn_par(3, 4, |row, col| (row as f64).powf(col as f64));
+    ///
+    /// assert_eq!(mat.get(2, 3).unwrap(), 8.0);
+    /// ```
+    pub fn from_fn_par<F>(rows: usize, cols: usize, f: F) -> Matrix<T>
+    where
+        F: Fn(usize, usize) -> T + Sync,
+    {
+        assert!(rows > 0 && cols > 0);
+
+        Matrix {
+            rows,
+            cols,
+            data: (0..rows * cols)
+                .into_par_iter()
+                .map(|i| f(i / cols, i % cols))
+                .collect(),
+        }
+    }
+}
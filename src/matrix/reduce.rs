@@ -0,0 +1,330 @@
+use super::Matrix;
+use num_traits::{Float, One, Zero};
+use std::ops::{AddAssign, Mul};
+
+impl<T> Matrix<T> {
+    /// Count the cells for which the predicate returns `true`.
+    /// Cells are visited row by row.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, vec![-2, 5, 0, 3, -1, 7]);
+    ///
+    /// assert_eq!(mat.count(|n| *n > 0), 3);
+    /// assert_eq!(mat.count(|n| *n < 0), 2);
+    /// ```
+    pub fn count<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.data.iter().filter(|n| pred(n)).count()
+    }
+
+    /// Check whether the predicate returns `true` for every cell.
+    /// Cells are visited row by row, stopping at the first one that fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, vec![1, 5, 2, 7]);
+    ///
+    /// assert!(mat.all(|n| *n > 0));
+    /// assert!(!mat.all(|n| *n > 1));
+    /// ```
+    pub fn all<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.data.iter().all(pred)
+    }
+
+    /// Check whether the predicate returns `true` for at least one cell.
+    /// Cells are visited row by row, stopping at the first one that passes.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, vec![1, -5, 2, 7]);
+    ///
+    /// assert!(mat.any(|n| *n < 0));
+    /// assert!(!mat.any(|n| *n > 10));
+    /// ```
+    pub fn any<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.data.iter().any(pred)
+    }
+
+    /// Check whether every cell of a float matrix is finite, neither infinite nor NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, -5.0, 2.0, 7.0]);
+    /// assert!(mat.is_finite());
+    ///
+    /// mat.set(1, 0, f64::NAN);
+    /// assert!(!mat.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool
+    where
+        T: Float,
+    {
+        self.all(|n| n.is_finite())
+    }
+
+    /// Count the cells that are not equal to zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 3, vec![
+    ///     0, 0, 4,
+    ///     0, 1, 0,
+    ///     9, 0, 0,
+    /// ]);
+    ///
+    /// assert_eq!(mat.count_nonzero(), 3);
+    /// ```
+    pub fn count_nonzero(&self) -> usize
+    where
+        T: Zero + PartialEq,
+    {
+        self.count(|n| !n.is_zero())
+    }
+
+    /// Count the cells falling into each of `bins` equal-width intervals over `[min, max)`.  
+    /// Cells outside of the range, including `max` itself, and NaN cells are not counted.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::from_iter(4, 5, (0..).map(f64::from));
+    /// assert_eq!(mat.histogram(4, 0.0, 20.0), vec![5, 5, 5, 5]);
+    ///
+    /// // Lower bounds are included, upper bounds are not
+    /// let edges: Matrix<f64> = Matrix::from_iter(1, 5, vec![-0.5, 0.0, 0.5, 1.0, f64::NAN]);
+    /// assert_eq!(edges.histogram(2, 0.0, 1.0), vec![1, 1]);
+    /// assert!(edges.histogram(0, 0.0, 1.0).is_empty());
+    /// ```
+    pub fn histogram(&self, bins: usize, min: T, max: T) -> Vec<usize>
+    where
+        T: Float,
+    {
+        let mut counts = vec![0; bins];
+        if bins == 0 {
+            return counts;
+        }
+
+        let scale = T::from(bins).unwrap() / (max - min);
+
+        for &value in &self.data {
+            if value >= min && value < max {
+                let bin = ((value - min) * scale).to_usize().unwrap_or(0);
+                counts[bin.min(bins - 1)] += 1;
+            }
+        }
+
+        counts
+    }
+
+    /// Compute the sum of all cells.  
+    /// Cells are added by reference, so no cell is cloned.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(mat.sum(), 21);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Zero + for<'a> AddAssign<&'a T>,
+    {
+        let mut sum = T::zero();
+        self.data.iter().for_each(|value| sum += value);
+        sum
+    }
+
+    /// Compute the sum of the main diagonal of a *N*x*N* Matrix.  
+    /// Returns `None` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 3, 1..);
+    ///
+    /// assert_eq!(mat.trace(), Some(1 + 5 + 9));
+    /// assert!(Matrix::<i32>::zero(2, 3).trace().is_none());
+    /// ```
+    pub fn trace(&self) -> Option<T>
+    where
+        T: Zero + for<'a> AddAssign<&'a T>,
+    {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let mut trace = T::zero();
+        self.iter_diagonal().for_each(|value| trace += value);
+        Some(trace)
+    }
+
+    /// Compute the sum of every row.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(mat.row_sums(), vec![6, 15]);
+    /// ```
+    pub fn row_sums(&self) -> Vec<T>
+    where
+        T: Zero + for<'a> AddAssign<&'a T>,
+    {
+        self.data
+            .chunks(self.cols)
+            .map(|row| {
+                let mut sum = T::zero();
+                row.iter().for_each(|value| sum += value);
+                sum
+            })
+            .collect()
+    }
+
+    /// Compute the sum of every column.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(mat.col_sums(), vec![5, 7, 9]);
+    /// ```
+    pub fn col_sums(&self) -> Vec<T>
+    where
+        T: Zero + for<'a> AddAssign<&'a T>,
+    {
+        let mut sums: Vec<T> = (0..self.cols).map(|_| T::zero()).collect();
+        for row in self.data.chunks(self.cols) {
+            sums.iter_mut()
+                .zip(row)
+                .for_each(|(sum, value)| *sum += value);
+        }
+        sums
+    }
+
+    /// Compute the product of all cells.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(mat.product(), 720);
+    /// ```
+    pub fn product(&self) -> T
+    where
+        T: Mul<Output = T> + One + Clone,
+    {
+        self.data
+            .iter()
+            .fold(T::one(), |acc, value| acc * value.clone())
+    }
+
+    /// Compute the product of the cells of the main diagonal.  
+    /// For a triangular matrix, this is its determinant.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let upper: Matrix<i32> = Matrix::new([
+    ///     [2, 7, -1],
+    ///     [0, 3, 5],
+    ///     [0, 0, -4],
+    /// ]);
+    ///
+    /// assert_eq!(upper.diagonal_product(), -24);
+    /// assert_eq!(upper.determinant(), Some(upper.diagonal_product()));
+    /// ```
+    pub fn diagonal_product(&self) -> T
+    where
+        T: Mul<Output = T> + One + Clone,
+    {
+        self.iter_diagonal()
+            .fold(T::one(), |acc, value| acc * value.clone())
+    }
+
+    /// Compute the mean of all cells.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[1.0, 2.0], [3.0, 6.0]]);
+    ///
+    /// assert_eq!(mat.mean(), 3.0);
+    /// ```
+    pub fn mean(&self) -> T
+    where
+        T: Float,
+    {
+        let sum = self.data.iter().fold(T::zero(), |sum, &value| sum + value);
+        sum / T::from(self.data.len()).unwrap()
+    }
+
+    /// Compute the variance of all cells, the sum of squared deviations from the mean
+    /// divided by `len() - ddof`.  
+    /// Use a `ddof` of `0` for the population variance, and `1` for the (unbiased) sample variance.  
+    /// Returns NaN if `ddof` is not smaller than the number of cells.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[1.0, 2.0], [3.0, 6.0]]);
+    ///
+    /// // Squared deviations: 4 + 1 + 0 + 9 = 14
+    /// assert_eq!(mat.variance(0), 3.5);
+    /// assert!((mat.variance(1) - 14.0 / 3.0).abs() < 1e-12);
+    /// assert!(mat.variance(4).is_nan());
+    /// ```
+    pub fn variance(&self, ddof: usize) -> T
+    where
+        T: Float,
+    {
+        if ddof >= self.data.len() {
+            return T::nan();
+        }
+
+        let mean = self.mean();
+        let squares = self.data.iter().fold(T::zero(), |sum, &value| {
+            sum + (value - mean) * (value - mean)
+        });
+        squares / T::from(self.data.len() - ddof).unwrap()
+    }
+
+    /// Compute the standard deviation of all cells, the square root of `variance(ddof)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::new([[2.0, 4.0], [4.0, 6.0]]);
+    ///
+    /// assert_eq!(mat.std_dev(0), 2.0f64.sqrt());
+    /// ```
+    pub fn std_dev(&self, ddof: usize) -> T
+    where
+        T: Float,
+    {
+        self.variance(ddof).sqrt()
+    }
+}
@@ -0,0 +1,717 @@
+use super::Matrix;
+use num_traits::Zero;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+impl<T> Matrix<T> {
+    /// Try to construct the *rows*x*cols* Matrix starting at the given row & column.  
+    /// Returns `None` if the block is empty or extends past the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    /// let sub = mat.submatrix(1, 2, 2, 3).unwrap();
+    ///
+    /// assert_eq!(sub, Matrix::from_iter(2, 3, vec![8, 9, 10, 14, 15, 16]));
+    ///
+    /// assert!(mat.submatrix(2, 0, 2, 2).is_none());
+    /// ```
+    pub fn submatrix(
+        &self,
+        row_start: usize,
+        col_start: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        if rows == 0 || cols == 0 || row_start + rows > self.rows || col_start + cols > self.cols {
+            return None;
+        }
+
+        Some(Matrix::from_iter(
+            rows,
+            cols,
+            (row_start..row_start + rows).flat_map(|row| {
+                let start = col_start + row * self.cols;
+                self.data[start..start + cols].iter().cloned()
+            }),
+        ))
+    }
+
+    /// Try to copy the cells of `block` into the region starting at the given row & column.  
+    /// Returns `false` (and leaves the matrix untouched) if the block would extend past the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mut mat: Matrix<i32> = Matrix::zero(4, 4);
+    /// let block = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert!(mat.set_submatrix(2, 2, &block));
+    /// assert_eq!(mat.submatrix(2, 2, 2, 2).unwrap(), block);
+    /// assert_eq!(mat.count_nonzero(), 4);
+    ///
+    /// assert!(!mat.set_submatrix(3, 0, &block));
+    /// ```
+    pub fn set_submatrix(&mut self, row_start: usize, col_start: usize, block: &Matrix<T>) -> bool
+    where
+        T: Clone,
+    {
+        if row_start + block.rows > self.rows || col_start + block.cols > self.cols {
+            return false;
+        }
+
+        for (row, values) in block.data.chunks(block.cols).enumerate() {
+            let start = col_start + (row_start + row) * self.cols;
+            self.data[start..start + block.cols].clone_from_slice(values);
+        }
+        true
+    }
+
+    /// Split the matrix into four blocks at the given row & column.  
+    /// Returns the top-left, top-right, bottom-left and bottom-right blocks,
+    /// or `None` if `row` or `col` is `0` or not inside the matrix, as a block would be empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(4, 4, 0..);
+    /// let (tl, tr, bl, br) = mat.partition(2, 2).unwrap();
+    ///
+    /// assert_eq!(tl, Matrix::new([[0, 1], [4, 5]]));
+    /// assert_eq!(tr, Matrix::new([[2, 3], [6, 7]]));
+    /// assert_eq!(bl, Matrix::new([[8, 9], [12, 13]]));
+    /// assert_eq!(br, Matrix::new([[10, 11], [14, 15]]));
+    ///
+    /// assert!(mat.partition(0, 2).is_none());
+    /// assert!(mat.partition(2, 4).is_none());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn partition(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Option<(Matrix<T>, Matrix<T>, Matrix<T>, Matrix<T>)>
+    where
+        T: Clone,
+    {
+        if row == 0 || col == 0 || row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let (rows, cols) = (self.rows - row, self.cols - col);
+        Some((
+            self.submatrix(0, 0, row, col)?,
+            self.submatrix(0, col, row, cols)?,
+            self.submatrix(row, 0, rows, col)?,
+            self.submatrix(row, col, rows, cols)?,
+        ))
+    }
+
+    /// Find the smallest block containing all non-zero cells.  
+    /// Returns `(row_start, col_start, rows, cols)` of that block,
+    /// or `None` if all cells are zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(4, 5, vec![
+    ///     0, 0, 0, 0, 0,
+    ///     0, 0, 1, 2, 0,
+    ///     0, 3, 0, 0, 0,
+    ///     0, 0, 0, 0, 0,
+    /// ]);
+    ///
+    /// assert_eq!(mat.nonzero_bounding_box(), Some((1, 1, 2, 3)));
+    /// assert_eq!(Matrix::<i32>::zero(2, 2).nonzero_bounding_box(), None);
+    /// ```
+    pub fn nonzero_bounding_box(&self) -> Option<(usize, usize, usize, usize)>
+    where
+        T: Zero + PartialEq,
+    {
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+
+        for (i, value) in self.data.iter().enumerate() {
+            if value.is_zero() {
+                continue;
+            }
+
+            let (row, col) = (i / self.cols, i % self.cols);
+            bounds = Some(match bounds {
+                None => (row, col, row, col),
+                Some((r0, c0, r1, c1)) => (r0.min(row), c0.min(col), r1.max(row), c1.max(col)),
+            });
+        }
+
+        bounds.map(|(r0, c0, r1, c1)| (r0, c0, r1 - r0 + 1, c1 - c0 + 1))
+    }
+
+    /// Construct the smallest submatrix containing all non-zero cells.  
+    /// Returns `None` if all cells are zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(4, 5, vec![
+    ///     0, 0, 0, 0, 0,
+    ///     0, 0, 1, 2, 0,
+    ///     0, 3, 0, 0, 0,
+    ///     0, 0, 0, 0, 0,
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     mat.cropped_to_nonzero().unwrap(),
+    ///     Matrix::from_iter(2, 3, vec![0, 1, 2, 3, 0, 0])
+    /// );
+    /// ```
+    pub fn cropped_to_nonzero(&self) -> Option<Matrix<T>>
+    where
+        T: Zero + PartialEq + Clone,
+    {
+        let (row_start, col_start, rows, cols) = self.nonzero_bounding_box()?;
+        self.submatrix(row_start, col_start, rows, cols)
+    }
+
+    /// Construct a larger matrix by replacing each cell with the block returned by `f`.  
+    /// All blocks must share the same *bh*x*bw* shape,
+    /// the result is a *(rows * bh)*x*(cols * bw)* Matrix with blocks laid out like their cells.  
+    /// Returns `None` if the blocks do not all have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    /// let upscaled = mat.expand(|n| Matrix::from_iter(2, 2, std::iter::repeat(*n))).unwrap();
+    ///
+    /// assert_eq!(upscaled, Matrix::new([
+    ///     [1, 1, 2, 2],
+    ///     [1, 1, 2, 2],
+    ///     [3, 3, 4, 4],
+    ///     [3, 3, 4, 4],
+    /// ]));
+    ///
+    /// assert!(mat.expand(|n| Matrix::zero(*n as usize, 1)).is_none());
+    /// ```
+    pub fn expand<F: FnMut(&T) -> Matrix<T>>(&self, f: F) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        let blocks: Vec<Matrix<T>> = self.data.iter().map(f).collect();
+        let (bh, bw) = (blocks[0].rows, blocks[0].cols);
+        if blocks
+            .iter()
+            .any(|block| block.rows != bh || block.cols != bw)
+        {
+            return None;
+        }
+
+        let mut data = Vec::with_capacity(self.data.len() * bh * bw);
+        for row in 0..self.rows {
+            for block_row in 0..bh {
+                for col in 0..self.cols {
+                    let block = &blocks[col + row * self.cols];
+                    let start = block_row * bw;
+                    data.extend_from_slice(&block.data[start..start + bw]);
+                }
+            }
+        }
+
+        Some(Matrix {
+            rows: self.rows * bh,
+            cols: self.cols * bw,
+            data,
+        })
+    }
+
+    /// Construct a new matrix keeping only the rows for which `f(index, row)` returns `true`.  
+    /// Returns `None` if no row is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(4, 2, 0..);
+    /// let even = mat.retain_rows(|i, _| i % 2 == 0).unwrap();
+    ///
+    /// assert_eq!(even, Matrix::new([[0, 1], [4, 5]]));
+    ///
+    /// assert!(mat.retain_rows(|_, row| row[0] > 10).is_none());
+    /// ```
+    pub fn retain_rows<F: FnMut(usize, &[T]) -> bool>(&self, mut f: F) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        let mut rows = 0;
+        let mut data = Vec::new();
+        for (i, row) in self.data.chunks(self.cols).enumerate() {
+            if f(i, row) {
+                rows += 1;
+                data.extend_from_slice(row);
+            }
+        }
+
+        if rows == 0 {
+            return None;
+        }
+
+        Some(Matrix {
+            rows,
+            cols: self.cols,
+            data,
+        })
+    }
+
+    /// Construct a new matrix keeping only the columns for which `f(index, col)` returns `true`.  
+    /// Returns `None` if no column is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    /// let kept = mat.retain_cols(|_, col| col.iter().sum::<usize>() != 5).unwrap();
+    ///
+    /// assert_eq!(kept, Matrix::new([[0, 2], [3, 5]]));
+    /// ```
+    pub fn retain_cols<F: FnMut(usize, &[T]) -> bool>(&self, mut f: F) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        let mut column = Vec::with_capacity(self.rows);
+        let kept: Vec<usize> = (0..self.cols)
+            .filter(|&col| {
+                column.clear();
+                column.extend(self.get_col(col).unwrap().cloned());
+                f(col, &column)
+            })
+            .collect();
+
+        if kept.is_empty() {
+            return None;
+        }
+
+        Some(Matrix::from_iter(
+            self.rows,
+            kept.len(),
+            (0..self.rows).flat_map(|row| {
+                kept.iter()
+                    .map(move |&col| self.data[col + row * self.cols].clone())
+            }),
+        ))
+    }
+
+    /// Compute a hash for each row, using the standard library's default hasher.  
+    /// Identical rows always get the same hash, which makes finding duplicate rows cheap.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 2, vec![1, 2, 3, 4, 1, 2]);
+    /// let hashes = mat.row_hashes();
+    ///
+    /// assert_eq!(hashes.len(), 3);
+    /// assert_eq!(hashes[0], hashes[2]);
+    /// assert_ne!(hashes[0], hashes[1]);
+    /// ```
+    pub fn row_hashes(&self) -> Vec<u64>
+    where
+        T: Hash,
+    {
+        self.data
+            .chunks(self.cols)
+            .map(|row| {
+                let mut hasher = DefaultHasher::new();
+                row.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect()
+    }
+
+    /// Construct a new matrix where consecutive duplicate rows are only kept once.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(4, 2, vec![1, 2, 1, 2, 3, 4, 1, 2]);
+    ///
+    /// assert_eq!(mat.dedup_rows(), Matrix::from_iter(3, 2, vec![1, 2, 3, 4, 1, 2]));
+    /// ```
+    pub fn dedup_rows(&self) -> Matrix<T>
+    where
+        T: Hash + PartialEq + Clone,
+    {
+        let hashes = self.row_hashes();
+        let rows: Vec<&[T]> = self.data.chunks(self.cols).collect();
+
+        let mut result = Matrix {
+            rows: 1,
+            cols: self.cols,
+            data: rows[0].to_vec(),
+        };
+        for i in 1..self.rows {
+            if hashes[i] != hashes[i - 1] || rows[i] != rows[i - 1] {
+                result.rows += 1;
+                result.data.extend_from_slice(rows[i]);
+            }
+        }
+        result
+    }
+
+    /// Returns the groups of row indices with identical contents, in increasing order.  
+    /// Only rows appearing more than once are part of a group.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(5, 2, vec![1, 2, 3, 4, 1, 2, 5, 6, 1, 2]);
+    ///
+    /// assert_eq!(mat.duplicate_row_groups(), vec![vec![0, 2, 4]]);
+    /// ```
+    pub fn duplicate_row_groups(&self) -> Vec<Vec<usize>>
+    where
+        T: PartialEq,
+    {
+        let rows: Vec<&[T]> = self.row_slices().collect();
+
+        let mut grouped = vec![false; self.rows];
+        let mut groups = Vec::new();
+        for i in 0..self.rows {
+            if grouped[i] {
+                continue;
+            }
+
+            let mut group = vec![i];
+            for j in i + 1..self.rows {
+                if !grouped[j] && rows[i] == rows[j] {
+                    grouped[j] = true;
+                    group.push(j);
+                }
+            }
+            if group.len() > 1 {
+                groups.push(group);
+            }
+        }
+        groups
+    }
+
+    /// Check whether any two rows have identical contents.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// assert!(Matrix::from_iter(3, 2, vec![1, 2, 3, 4, 1, 2]).has_duplicate_rows());
+    /// assert!(!Matrix::from_iter(3, 2, vec![1, 2, 3, 4, 2, 1]).has_duplicate_rows());
+    /// ```
+    pub fn has_duplicate_rows(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let rows: Vec<&[T]> = self.row_slices().collect();
+        (0..self.rows).any(|i| rows[i + 1..].contains(&rows[i]))
+    }
+
+    /// Compare the cells of both matrices in row-major order, ignoring their shapes.  
+    /// Use `==` to also compare the number of rows and columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let wide: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    /// let tall: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    ///
+    /// assert!(wide.elements_eq(&tall));
+    /// assert_ne!(wide, tall);
+    ///
+    /// assert!(!wide.elements_eq(&Matrix::from_iter(3, 2, 0..)));
+    /// ```
+    pub fn elements_eq(&self, other: &Matrix<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.data == other.data
+    }
+
+    /// Try to construct a new matrix with `row` inserted before row `at`.  
+    /// Returns `None` if `at` is larger than `rows` or `row` does not have `cols` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(mat.insert_row(1, &[0, 0]).unwrap(), Matrix::from_iter(3, 2, vec![1, 2, 0, 0, 3, 4]));
+    /// assert_eq!(mat.insert_row(2, &[5, 6]).unwrap(), Matrix::from_iter(3, 2, 1..));
+    ///
+    /// assert!(mat.insert_row(3, &[5, 6]).is_none());
+    /// assert!(mat.insert_row(0, &[5]).is_none());
+    /// ```
+    pub fn insert_row(&self, at: usize, row: &[T]) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        if at > self.rows || row.len() != self.cols {
+            return None;
+        }
+
+        let split = at * self.cols;
+        let mut data = Vec::with_capacity(self.data.len() + self.cols);
+        data.extend_from_slice(&self.data[..split]);
+        data.extend_from_slice(row);
+        data.extend_from_slice(&self.data[split..]);
+
+        Some(Matrix {
+            rows: self.rows + 1,
+            cols: self.cols,
+            data,
+        })
+    }
+
+    /// Try to construct a new matrix with `col` inserted before column `at`.  
+    /// Returns `None` if `at` is larger than `cols` or `col` does not have `rows` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[2, 3], [5, 6]]);
+    ///
+    /// assert_eq!(mat.insert_col(0, &[1, 4]).unwrap(), Matrix::from_iter(2, 3, 1..));
+    ///
+    /// assert!(mat.insert_col(3, &[1, 4]).is_none());
+    /// assert!(mat.insert_col(0, &[1, 4, 7]).is_none());
+    /// ```
+    pub fn insert_col(&self, at: usize, col: &[T]) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        if at > self.cols || col.len() != self.rows {
+            return None;
+        }
+
+        let mut data = Vec::with_capacity(self.data.len() + self.rows);
+        for (row, value) in self.data.chunks(self.cols).zip(col) {
+            data.extend_from_slice(&row[..at]);
+            data.push(value.clone());
+            data.extend_from_slice(&row[at..]);
+        }
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: self.cols + 1,
+            data,
+        })
+    }
+
+    /// Try to split the matrix into consecutive blocks of `chunk` rows,
+    /// e.g. to iterate over a batch of matrices stacked on top of each other.  
+    /// Returns `None` if `chunk` is zero or does not divide the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let batch: Matrix<usize> = Matrix::from_iter(6, 2, 0..);
+    /// let blocks: Vec<_> = batch.chunk_rows(2).unwrap().collect();
+    ///
+    /// assert_eq!(blocks.len(), 3);
+    /// assert_eq!(blocks[0], Matrix::new([[0, 1], [2, 3]]));
+    /// assert_eq!(blocks[2], Matrix::new([[8, 9], [10, 11]]));
+    ///
+    /// assert!(batch.chunk_rows(4).is_none());
+    /// ```
+    pub fn chunk_rows(&self, chunk: usize) -> Option<impl Iterator<Item = Matrix<T>> + '_>
+    where
+        T: Clone,
+    {
+        if chunk == 0 || self.rows % chunk != 0 {
+            return None;
+        }
+
+        Some(self.data.chunks(chunk * self.cols).map(move |data| Matrix {
+            rows: chunk,
+            cols: self.cols,
+            data: data.to_vec(),
+        }))
+    }
+
+    /// Try to construct a new matrix whose row `i` is the row `indices[i]` of this matrix.  
+    /// Indices may be repeated or omitted.  
+    /// Returns `None` if `indices` is empty or an index is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 2, 0..);
+    ///
+    /// assert_eq!(mat.gather_rows(&[0, 0, 2]).unwrap(), Matrix::from_iter(3, 2, vec![0, 1, 0, 1, 4, 5]));
+    /// assert!(mat.gather_rows(&[1, 3]).is_none());
+    /// assert!(mat.gather_rows(&[]).is_none());
+    /// ```
+    pub fn gather_rows(&self, indices: &[usize]) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        if indices.is_empty() || indices.iter().any(|&row| row >= self.rows) {
+            return None;
+        }
+
+        let mut data = Vec::with_capacity(indices.len() * self.cols);
+        for &row in indices {
+            data.extend_from_slice(&self.data[row * self.cols..(row + 1) * self.cols]);
+        }
+
+        Some(Matrix {
+            rows: indices.len(),
+            cols: self.cols,
+            data,
+        })
+    }
+
+    /// Try to construct a new matrix whose column `i` is the column `indices[i]` of this matrix.  
+    /// Indices may be repeated or omitted.  
+    /// Returns `None` if `indices` is empty or an index is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.gather_cols(&[2, 2]).unwrap(), Matrix::from_iter(2, 2, vec![2, 2, 5, 5]));
+    /// assert!(mat.gather_cols(&[3]).is_none());
+    /// ```
+    pub fn gather_cols(&self, indices: &[usize]) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        if indices.is_empty() || indices.iter().any(|&col| col >= self.cols) {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: indices.len(),
+            data: self
+                .data
+                .chunks(self.cols)
+                .flat_map(|row| indices.iter().map(move |&col| row[col].clone()))
+                .collect(),
+        })
+    }
+
+    /// Try to compute the permutation that sorts the rows by the values in column `col`,
+    /// in ascending order.  
+    /// The sort is stable: rows with equal keys keep their order.
+    /// Keys that are not comparable to themselves, like NaN, are placed last,
+    /// the other keys must be totally ordered.  
+    /// Returns `None` if `col` is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 30], [2, 10], [3, 20]]);
+    ///
+    /// assert_eq!(mat.argsort_by_col(1), Some(vec![1, 2, 0]));
+    /// assert_eq!(mat.argsort_by_col(0), Some(vec![0, 1, 2]));
+    /// assert!(mat.argsort_by_col(2).is_none());
+    ///
+    /// let keys = Matrix::from_fn(40, 1, |row, _| {
+    ///     if row % 3 == 0 { f64::NAN } else { (row * 7 % 40) as f64 }
+    /// });
+    /// let order = keys.argsort_by_col(0).unwrap();
+    /// let sorted: Vec<f64> = order.iter().map(|&row| keys.get(row, 0).unwrap()).collect();
+    ///
+    /// assert!(sorted[..26].windows(2).all(|pair| pair[0] <= pair[1]));
+    /// assert!(sorted[26..].iter().all(|key| key.is_nan()));
+    /// assert_eq!(order[26..], (0..40).step_by(3).collect::<Vec<_>>()[..]);
+    /// ```
+    pub fn argsort_by_col(&self, col: usize) -> Option<Vec<usize>>
+    where
+        T: PartialOrd + Clone,
+    {
+        if col >= self.cols {
+            return None;
+        }
+
+        let key = |row: usize| &self.data[col + row * self.cols];
+        let comparable = |row: usize| key(row).partial_cmp(key(row)).is_some();
+        let mut indices: Vec<usize> = (0..self.rows).collect();
+        indices.sort_by(|&a, &b| match key(a).partial_cmp(key(b)) {
+            Some(ordering) => ordering,
+            None => comparable(b).cmp(&comparable(a)),
+        });
+        Some(indices)
+    }
+
+    /// Try to construct a new matrix with the rows sorted by the values in column `col`,
+    /// in ascending order (see `argsort_by_col`).  
+    /// Returns `None` if `col` is outside of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 30], [2, 10], [3, 20]]);
+    ///
+    /// assert_eq!(mat.sorted_by_col(1).unwrap(), Matrix::new([[2, 10], [3, 20], [1, 30]]));
+    /// assert!(mat.sorted_by_col(2).is_none());
+    /// ```
+    pub fn sorted_by_col(&self, col: usize) -> Option<Matrix<T>>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.gather_rows(&self.argsort_by_col(col)?)
+    }
+
+    /// Try to construct a *rows*x*cols* matrix from the cells read column by column
+    /// (see `iter_col_major`), laid out row by row in the new shape.  
+    /// This fixes data that was stored column-major but loaded as row-major:
+    /// with the transposed shape, it gives back the transpose.  
+    /// Returns `None` if `rows * cols` is not the number of cells or either is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    /// let relaid = mat.reinterpret_col_major(3, 2).unwrap();
+    ///
+    /// assert_eq!(relaid, Matrix::new([[0, 3], [1, 4], [2, 5]]));
+    /// assert_eq!(relaid.get(2, 0), mat.get(0, 2));
+    /// assert_eq!(relaid, mat.transpose());
+    ///
+    /// assert_eq!(mat.reinterpret_col_major(1, 6).unwrap(), Matrix::new([[0, 3, 1, 4, 2, 5]]));
+    /// assert!(mat.reinterpret_col_major(4, 2).is_none());
+    /// ```
+    pub fn reinterpret_col_major(&self, rows: usize, cols: usize) -> Option<Matrix<T>>
+    where
+        T: Clone,
+    {
+        if rows == 0 || cols == 0 || rows * cols != self.data.len() {
+            return None;
+        }
+
+        Some(Matrix {
+            rows,
+            cols,
+            data: self.iter_col_major().cloned().collect(),
+        })
+    }
+}
@@ -95,6 +95,31 @@ impl<T: Neg<Output = T>> Neg for Matrix<T> {
 
 // Mul implementation
 
+/// Number of cells (`rows * cols`) of the product above which the by-value `Mul`
+/// computes its cells in parallel.  
+/// Smaller products stay serial, as spawning the work would cost more than it saves.
+#[cfg(feature = "rayon")]
+pub const PARALLEL_MUL_THRESHOLD: usize = 4096;
+
+fn dot<T>(lhs: &Matrix<T>, rhs: &Matrix<T>, row: usize, col: usize) -> T
+where
+    T: Mul<Output = T> + Add<Output = T> + Copy,
+{
+    let row = lhs.get_row(row).unwrap();
+    let col = rhs.get_col(col).unwrap();
+
+    let mut iter = row.zip(col);
+    let (a, b) = iter.next().unwrap();
+    let mut acc = *a * *b;
+
+    for (a, b) in iter {
+        acc = acc + *a * *b;
+    }
+
+    acc
+}
+
+#[cfg(not(feature = "rayon"))]
 impl<T> Mul<Matrix<T>> for Matrix<T>
 where
     T: Mul<Output = T> + Add<Output = T> + Copy,
@@ -107,27 +132,35 @@ where
         Matrix {
             rows: self.rows,
             cols: rhs.cols,
-            data: {
-                let mut data = Vec::with_capacity(self.rows * rhs.cols);
+            data: (0..self.rows * rhs.cols)
+                .map(|i| dot(&self, &rhs, i / rhs.cols, i % rhs.cols))
+                .collect(),
+        }
+    }
+}
 
-                for row in 0..self.rows {
-                    for col in 0..rhs.cols {
-                        let row = self.get_row(row).unwrap();
-                        let col = rhs.get_col(col).unwrap();
+#[cfg(feature = "rayon")]
+impl<T> Mul<Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Copy + Send + Sync,
+{
+    type Output = Matrix<T>;
 
-                        let mut iter = row.zip(col);
-                        let (a, b) = iter.next().unwrap();
-                        let mut acc = *a * *b;
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        use rayon::prelude::*;
 
-                        for (a, b) in iter {
-                            acc = acc + *a * *b;
-                        }
+        assert!(self.cols == rhs.rows);
 
-                        data.push(acc);
-                    }
-                }
+        let len = self.rows * rhs.cols;
+        let cell = |i: usize| dot(&self, &rhs, i / rhs.cols, i % rhs.cols);
 
-                data
+        Matrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            data: if len > PARALLEL_MUL_THRESHOLD {
+                (0..len).into_par_iter().map(cell).collect()
+            } else {
+                (0..len).map(cell).collect()
             },
         }
     }
@@ -0,0 +1,636 @@
+use super::Matrix;
+use num_traits::{Float, Zero};
+use std::ops::{Add, Sub};
+
+/// How `Matrix::pad` fills the cells outside of the original matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PadMode<T> {
+    /// Fill with the given value.
+    Constant(T),
+    /// Repeat the closest border cell: `a b c` becomes `a a | a b c | c c`.
+    Edge,
+    /// Mirror the matrix around its border cells, without repeating them:
+    /// `a b c` becomes `c b | a b c | b a`.
+    Reflect,
+}
+
+// Index of the original cell used for the padded index `i` of an axis of length `len`,
+// `None` if the cell is filled with a constant.
+fn pad_source<T>(i: isize, len: usize, mode: &PadMode<T>) -> Option<usize> {
+    let last = len as isize - 1;
+    if (0..=last).contains(&i) {
+        return Some(i as usize);
+    }
+
+    match mode {
+        PadMode::Constant(_) => None,
+        PadMode::Edge => Some(i.clamp(0, last) as usize),
+        PadMode::Reflect if len == 1 => Some(0),
+        PadMode::Reflect => {
+            let period = 2 * last;
+            let i = i.rem_euclid(period);
+            Some(if i > last { period - i } else { i } as usize)
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Construct a new matrix with the rows circularly shifted down by `by`
+    /// (up if `by` is negative), wrapping around the matrix.  
+    /// Shifting by any multiple of `rows` gives back the same matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 2, 0..);
+    ///
+    /// assert_eq!(mat.roll_rows(1), Matrix::from_iter(3, 2, vec![4, 5, 0, 1, 2, 3]));
+    /// assert_eq!(mat.roll_rows(-1), Matrix::from_iter(3, 2, vec![2, 3, 4, 5, 0, 1]));
+    /// assert_eq!(mat.roll_rows(7), mat.roll_rows(1));
+    /// ```
+    pub fn roll_rows(&self, by: isize) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let shift = by.rem_euclid(self.rows as isize) as usize;
+        let mut data = self.data.clone();
+        data.rotate_right(shift * self.cols);
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// Construct a new matrix with the columns circularly shifted right by `by`
+    /// (left if `by` is negative), wrapping around the matrix.  
+    /// Shifting by any multiple of `cols` gives back the same matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.roll_cols(1), Matrix::from_iter(2, 3, vec![2, 0, 1, 5, 3, 4]));
+    /// assert_eq!(mat.roll_cols(-4), Matrix::from_iter(2, 3, vec![1, 2, 0, 4, 5, 3]));
+    /// ```
+    pub fn roll_cols(&self, by: isize) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let shift = by.rem_euclid(self.cols as isize) as usize;
+        let mut data = self.data.clone();
+        data.chunks_mut(self.cols)
+            .for_each(|row| row.rotate_right(shift));
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// Construct a new matrix with the quadrants swapped diagonally, moving the top-left
+    /// cell to the center, like numpy's `fftshift`.  
+    /// For odd dimensions the top-left quadrant is the larger one, so the shift is
+    /// `rows / 2` rows and `cols / 2` columns. `ifftshift` reverses it.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(4, 4, 0..);
+    /// let shifted = mat.fftshift();
+    ///
+    /// assert_eq!(shifted.get(2, 2).unwrap(), mat.get(0, 0).unwrap());
+    /// assert_eq!(shifted.get(0, 0).unwrap(), mat.get(2, 2).unwrap());
+    /// assert_eq!(shifted.get(0, 2).unwrap(), mat.get(2, 0).unwrap());
+    /// assert_eq!(shifted.get(3, 1).unwrap(), mat.get(1, 3).unwrap());
+    ///
+    /// let odd: Matrix<usize> = Matrix::from_iter(3, 3, 0..);
+    /// assert_eq!(odd.fftshift(), Matrix::from_iter(3, 3, vec![8, 6, 7, 2, 0, 1, 5, 3, 4]));
+    /// assert_eq!(odd.fftshift().ifftshift(), odd);
+    /// ```
+    pub fn fftshift(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        self.roll_rows((self.rows / 2) as isize)
+            .roll_cols((self.cols / 2) as isize)
+    }
+
+    /// Construct a new matrix with the quadrants swapped back, undoing `fftshift`,
+    /// like numpy's `ifftshift`.  
+    /// Both are the same for even dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(3, 4, 0..);
+    ///
+    /// assert_eq!(mat.ifftshift().get(0, 0).unwrap(), mat.get(1, 2).unwrap());
+    /// assert_eq!(mat.ifftshift().fftshift(), mat);
+    /// ```
+    pub fn ifftshift(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        self.roll_rows(-((self.rows / 2) as isize))
+            .roll_cols(-((self.cols / 2) as isize))
+    }
+
+    /// Construct a larger matrix with `top`, `bottom`, `left` and `right` cells added around it,
+    /// filled according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::{Matrix, PadMode};
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(mat.pad(1, 1, 1, 1, PadMode::Constant(0)), Matrix::new([
+    ///     [0, 0, 0, 0],
+    ///     [0, 1, 2, 0],
+    ///     [0, 3, 4, 0],
+    ///     [0, 0, 0, 0],
+    /// ]));
+    ///
+    /// assert_eq!(mat.pad(1, 1, 1, 1, PadMode::Edge), Matrix::new([
+    ///     [1, 1, 2, 2],
+    ///     [1, 1, 2, 2],
+    ///     [3, 3, 4, 4],
+    ///     [3, 3, 4, 4],
+    /// ]));
+    ///
+    /// assert_eq!(mat.pad(1, 1, 1, 1, PadMode::Reflect), Matrix::new([
+    ///     [4, 3, 4, 3],
+    ///     [2, 1, 2, 1],
+    ///     [4, 3, 4, 3],
+    ///     [2, 1, 2, 1],
+    /// ]));
+    /// ```
+    pub fn pad(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        mode: PadMode<T>,
+    ) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let rows = top + self.rows + bottom;
+        let cols = left + self.cols + right;
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            let src_row = pad_source(row as isize - top as isize, self.rows, &mode);
+            for col in 0..cols {
+                let src_col = pad_source(col as isize - left as isize, self.cols, &mode);
+                data.push(match (src_row, src_col, &mode) {
+                    (Some(row), Some(col), _) => self.data[col + row * self.cols].clone(),
+                    (_, _, PadMode::Constant(value)) => value.clone(),
+                    _ => unreachable!(),
+                });
+            }
+        }
+
+        Matrix { rows, cols, data }
+    }
+
+    /// Try to downsample the matrix by replacing every non-overlapping
+    /// `block_rows`x`block_cols` block with its largest cell.  
+    /// Returns `None` if the block size is zero or does not divide the matrix dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([
+    ///     [1, 5, 2, 0],
+    ///     [3, 2, 8, 1],
+    ///     [0, 0, 4, 4],
+    ///     [7, 1, 4, 9],
+    /// ]);
+    ///
+    /// assert_eq!(mat.max_pool(2, 2).unwrap(), Matrix::new([[5, 8], [7, 9]]));
+    /// assert!(mat.max_pool(3, 2).is_none());
+    /// ```
+    pub fn max_pool(&self, block_rows: usize, block_cols: usize) -> Option<Matrix<T>>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.pool(block_rows, block_cols, |block| {
+            let first = block.next().unwrap();
+            block
+                .fold(first, |max, value| if value > max { value } else { max })
+                .clone()
+        })
+    }
+
+    /// Try to downsample the matrix by replacing every non-overlapping
+    /// `block_rows`x`block_cols` block with the mean of its cells.  
+    /// Returns `None` if the block size is zero or does not divide the matrix dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 4, (1..).map(f64::from));
+    ///
+    /// assert_eq!(mat.mean_pool(2, 2).unwrap(), Matrix::from_iter(1, 2, vec![3.5, 5.5]));
+    /// assert!(mat.mean_pool(2, 3).is_none());
+    /// ```
+    pub fn mean_pool(&self, block_rows: usize, block_cols: usize) -> Option<Matrix<T>>
+    where
+        T: Float,
+    {
+        let count = T::from(block_rows * block_cols)?;
+        self.pool(block_rows, block_cols, |block| {
+            block.fold(T::zero(), |sum, &value| sum + value) / count
+        })
+    }
+
+    fn pool<F: FnMut(&mut dyn Iterator<Item = &T>) -> T>(
+        &self,
+        block_rows: usize,
+        block_cols: usize,
+        mut reduce: F,
+    ) -> Option<Matrix<T>> {
+        if block_rows == 0
+            || block_cols == 0
+            || self.rows % block_rows != 0
+            || self.cols % block_cols != 0
+        {
+            return None;
+        }
+
+        let rows = self.rows / block_rows;
+        let cols = self.cols / block_cols;
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                let mut block = (0..block_rows).flat_map(|i| {
+                    let start = col * block_cols + (row * block_rows + i) * self.cols;
+                    &self.data[start..start + block_cols]
+                });
+                data.push(reduce(&mut block));
+            }
+        }
+
+        Some(Matrix { rows, cols, data })
+    }
+
+    /// Construct a new matrix keeping every `row_step`-th row and every `col_step`-th column,
+    /// starting from the first ones.
+    /// The result is a *ceil(rows / row_step)*x*ceil(cols / col_step)* matrix.
+    ///
+    /// # Panics
+    /// Panics if either `row_step` or `col_step` are equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(4, 4, 0..);
+    ///
+    /// assert_eq!(mat.subsample(2, 2), Matrix::new([[0, 2], [8, 10]]));
+    /// assert_eq!(mat.subsample(3, 1), Matrix::new([[0, 1, 2, 3], [12, 13, 14, 15]]));
+    /// ```
+    pub fn subsample(&self, row_step: usize, col_step: usize) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            row_step > 0 && col_step > 0,
+            "subsample steps must be positive"
+        );
+
+        let rows = self.rows.div_ceil(row_step);
+        let cols = self.cols.div_ceil(col_step);
+
+        Matrix {
+            rows,
+            cols,
+            data: self
+                .data
+                .chunks(self.cols)
+                .step_by(row_step)
+                .flat_map(|row| row.iter().step_by(col_step).cloned())
+                .collect(),
+        }
+    }
+
+    /// Construct a new matrix by applying `f` to every `win_rows`x`win_cols` window of the matrix,
+    /// like a convolution without padding (valid mode).
+    /// The result is a *(rows - win_rows + 1)*x*(cols - win_cols + 1)* matrix,
+    /// whose cell `(i, j)` is `f` of the window with `(i, j)` as top-left corner.
+    ///
+    /// # Panics
+    /// Panics if either `win_rows` or `win_cols` are equal to `0`
+    /// or larger than the matrix dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([
+    ///     [1, 9, 2, 8],
+    ///     [7, 3, 6, 4],
+    ///     [5, 0, 1, 2],
+    ///     [4, 6, 3, 9],
+    /// ]);
+    ///
+    /// let median = mat.stencil(3, 3, |window| {
+    ///     let mut values = window.clone().into_vec();
+    ///     values.sort();
+    ///     values[values.len() / 2]
+    /// });
+    /// assert_eq!(median, Matrix::new([[3, 3], [4, 3]]));
+    /// ```
+    pub fn stencil<F: FnMut(&Matrix<T>) -> T>(
+        &self,
+        win_rows: usize,
+        win_cols: usize,
+        mut f: F,
+    ) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            win_rows > 0 && win_rows <= self.rows && win_cols > 0 && win_cols <= self.cols,
+            "window of {}x{} does not fit in a {}x{} matrix",
+            win_rows,
+            win_cols,
+            self.rows,
+            self.cols
+        );
+
+        let rows = self.rows - win_rows + 1;
+        let cols = self.cols - win_cols + 1;
+
+        let mut window = Matrix {
+            rows: win_rows,
+            cols: win_cols,
+            data: vec![self.data[0].clone(); win_rows * win_cols],
+        };
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                for (i, window_row) in window.data.chunks_mut(win_cols).enumerate() {
+                    let start = col + (row + i) * self.cols;
+                    window_row.clone_from_slice(&self.data[start..start + win_cols]);
+                }
+                data.push(f(&window));
+            }
+        }
+
+        Matrix { rows, cols, data }
+    }
+
+    /// Try to construct the first differences between consecutive rows,
+    /// a *(rows - 1)*x*cols* matrix where each row is `next_row - row`, like numpy's `diff`.  
+    /// Returns `None` if the matrix has a single row.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 1, vec![1, 3, 6]);
+    ///
+    /// assert_eq!(mat.diff_rows().unwrap(), Matrix::from_iter(2, 1, vec![2, 3]));
+    /// assert!(Matrix::<i32>::zero(1, 3).diff_rows().is_none());
+    /// ```
+    pub fn diff_rows(&self) -> Option<Matrix<T>>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        if self.rows < 2 {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: self.rows - 1,
+            cols: self.cols,
+            data: self.data[self.cols..]
+                .iter()
+                .zip(&self.data)
+                .map(|(next, value)| next.clone() - value.clone())
+                .collect(),
+        })
+    }
+
+    /// Try to construct the first differences between consecutive columns,
+    /// a *rows*x*(cols - 1)* matrix where each column is `next_col - col`.  
+    /// Returns `None` if the matrix has a single column.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, vec![1, 4, 9, 0, -1, 1]);
+    ///
+    /// assert_eq!(mat.diff_cols().unwrap(), Matrix::from_iter(2, 2, vec![3, 5, -1, 2]));
+    /// assert!(Matrix::<i32>::zero(3, 1).diff_cols().is_none());
+    /// ```
+    pub fn diff_cols(&self) -> Option<Matrix<T>>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        if self.cols < 2 {
+            return None;
+        }
+
+        Some(Matrix {
+            rows: self.rows,
+            cols: self.cols - 1,
+            data: self
+                .data
+                .chunks(self.cols)
+                .flat_map(|row| row.windows(2).map(|pair| pair[1].clone() - pair[0].clone()))
+                .collect(),
+        })
+    }
+
+    /// Construct the integral image (summed-area table) of the matrix,
+    /// where cell `(i, j)` is the sum of all the cells `(k, l)` with `k <= i` and `l <= j`.  
+    /// See `rect_sum` to get the sum of any rectangle from it in constant time.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<i32> = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.integral_image(), Matrix::new([[1, 3, 6], [5, 12, 21]]));
+    /// ```
+    pub fn integral_image(&self) -> Matrix<T>
+    where
+        T: Add<Output = T> + Zero + Clone,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(self.data.len());
+        for (row, cells) in self.data.chunks(self.cols).enumerate() {
+            let mut row_sum = T::zero();
+            for (col, value) in cells.iter().enumerate() {
+                row_sum = row_sum + value.clone();
+                data.push(if row == 0 {
+                    row_sum.clone()
+                } else {
+                    row_sum.clone() + data[col + (row - 1) * self.cols].clone()
+                });
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// Compute the sum of the cells in the rectangle from `(r0, c0)` to `(r1, c1)` included,
+    /// with four lookups into the `integral` image built by `integral_image`.  
+    /// Intermediate values stay non-negative for non-negative cells, so unsigned types work too.
+    ///
+    /// # Panics
+    /// Panics if `r0 > r1`, `c0 > c1`, or `(r1, c1)` is outside of the integral image.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<u32> = Matrix::from_iter(4, 5, 0..);
+    /// let integral = mat.integral_image();
+    ///
+    /// let direct: u32 = (1..=2)
+    ///     .flat_map(|row| (2..=4).map(move |col| (row, col)))
+    ///     .map(|(row, col)| mat.get(row, col).unwrap())
+    ///     .sum();
+    /// assert_eq!(Matrix::rect_sum(&integral, 1, 2, 2, 4), direct);
+    ///
+    /// assert_eq!(Matrix::rect_sum(&integral, 0, 0, 3, 4), mat.sum());
+    /// assert_eq!(Matrix::rect_sum(&integral, 3, 1, 3, 1), 16);
+    /// ```
+    pub fn rect_sum(integral: &Matrix<T>, r0: usize, c0: usize, r1: usize, c1: usize) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Zero + Clone,
+    {
+        assert!(
+            r0 <= r1 && c0 <= c1 && r1 < integral.rows && c1 < integral.cols,
+            "invalid rectangle from ({}, {}) to ({}, {}) in a {}x{} integral image",
+            r0,
+            c0,
+            r1,
+            c1,
+            integral.rows,
+            integral.cols
+        );
+
+        let at = |row: usize, col: usize| integral.data[col + row * integral.cols].clone();
+        let above = if r0 > 0 { at(r0 - 1, c1) } else { T::zero() };
+        let left = if c0 > 0 { at(r1, c0 - 1) } else { T::zero() };
+        let corner = if r0 > 0 && c0 > 0 {
+            at(r0 - 1, c0 - 1)
+        } else {
+            T::zero()
+        };
+
+        at(r1, c1) + corner - above - left
+    }
+
+    /// Construct a new matrix rotated a quarter turn clockwise.
+    /// The rotated matrix has *cols* rows and *rows* columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.rotate90(), Matrix::from_iter(3, 2, vec![3, 0, 4, 1, 5, 2]));
+    /// ```
+    pub fn rotate90(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows, self.cols);
+        Matrix::from_fn(cols, rows, |row, col| {
+            self.data[row + (rows - 1 - col) * cols].clone()
+        })
+    }
+
+    /// Construct a new matrix rotated a half turn, with the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.rotate180(), Matrix::from_iter(2, 3, vec![5, 4, 3, 2, 1, 0]));
+    /// assert_eq!(mat.rotate180(), mat.rotate90().rotate90());
+    /// ```
+    pub fn rotate180(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// Construct a new matrix rotated a quarter turn counter-clockwise.
+    /// The rotated matrix has *cols* rows and *rows* columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.rotate270(), Matrix::from_iter(3, 2, vec![2, 5, 1, 4, 0, 3]));
+    /// assert_eq!(mat.rotate270().rotate90(), mat);
+    /// ```
+    pub fn rotate270(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows, self.cols);
+        Matrix::from_fn(cols, rows, |row, col| {
+            self.data[cols - 1 - row + col * cols].clone()
+        })
+    }
+
+    /// Construct a new matrix rotated clockwise by `quarter_turns` quarter turns.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_matrix::Matrix;
+    ///
+    /// let mat: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    ///
+    /// assert_eq!(mat.rotate(1), mat.rotate90());
+    /// assert_eq!(mat.rotate(2), mat.rotate180());
+    /// assert_eq!(mat.rotate(3), mat.rotate270());
+    /// assert_eq!(mat.rotate(4), mat);
+    /// ```
+    pub fn rotate(&self, quarter_turns: u32) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        match quarter_turns % 4 {
+            0 => self.clone(),
+            1 => self.rotate90(),
+            2 => self.rotate180(),
+            _ => self.rotate270(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use num_bigint::BigInt;
+use simple_matrix::Matrix;
+
+fn big_matrix() -> Matrix<BigInt> {
+    // Cells are 10^30 + n, far beyond the range of any primitive integer
+    let base: BigInt = BigInt::from(10).pow(30);
+    Matrix::from_iter(3, 3, (0..).map(|n| &base + BigInt::from(n)))
+}
+
+#[test]
+fn sum_bigint() {
+    let expected: BigInt = "9000000000000000000000000000036".parse().unwrap();
+    assert_eq!(big_matrix().sum(), expected);
+}
+
+#[test]
+fn trace_bigint() {
+    let expected: BigInt = "3000000000000000000000000000012".parse().unwrap();
+    assert_eq!(big_matrix().trace(), Some(expected));
+}
+
+#[test]
+fn row_and_col_sums_bigint() {
+    let mat = big_matrix();
+    let base: BigInt = BigInt::from(10).pow(30) * 3;
+
+    let row_sums: Vec<BigInt> = [3, 12, 21].map(|n| &base + BigInt::from(n)).to_vec();
+    let col_sums: Vec<BigInt> = [9, 12, 15].map(|n| &base + BigInt::from(n)).to_vec();
+
+    assert_eq!(mat.row_sums(), row_sums);
+    assert_eq!(mat.col_sums(), col_sums);
+}
@@ -0,0 +1,24 @@
+use simple_matrix::Matrix;
+
+#[test]
+fn collect_rows() {
+    let mat: Matrix<i32> = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]
+        .into_iter()
+        .collect();
+
+    assert_eq!(mat.rows(), 3);
+    assert_eq!(mat.cols(), 3);
+    assert_eq!(mat, Matrix::from_iter(3, 3, 1..));
+}
+
+#[test]
+#[should_panic(expected = "row 1 has 3 values, expected 2")]
+fn collect_ragged_rows() {
+    let _: Matrix<i32> = vec![vec![1, 2], vec![3, 4, 5]].into_iter().collect();
+}
+
+#[test]
+#[should_panic(expected = "cannot collect an empty matrix")]
+fn collect_no_rows() {
+    let _: Matrix<i32> = Vec::<Vec<i32>>::new().into_iter().collect();
+}
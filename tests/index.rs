@@ -0,0 +1,33 @@
+use simple_matrix::Matrix;
+
+#[test]
+fn index_in_bounds() {
+    let mut mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+
+    assert_eq!(mat[(1, 2)], 8);
+    assert_eq!(mat[[2, 5]], 17);
+
+    mat[(1, 2)] = 0;
+    assert_eq!(mat.get(1, 2), Some(0));
+}
+
+#[test]
+#[should_panic(expected = "cell (0, 100) is out of bounds for a 3x6 matrix")]
+fn index_tuple_col_does_not_wrap() {
+    let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    let _ = mat[(0, 100)];
+}
+
+#[test]
+#[should_panic(expected = "cell (0, 6) is out of bounds for a 3x6 matrix")]
+fn index_array_col_does_not_wrap() {
+    let mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    let _ = mat[[0, 6]];
+}
+
+#[test]
+#[should_panic(expected = "cell (1, 7) is out of bounds for a 3x6 matrix")]
+fn index_mut_col_does_not_wrap() {
+    let mut mat: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    mat[(1, 7)] = 0;
+}
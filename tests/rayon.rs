@@ -0,0 +1,38 @@
+#![cfg(feature = "rayon")]
+
+use simple_matrix::{Matrix, PARALLEL_MUL_THRESHOLD};
+
+fn product_matches_serial(rows: usize, inner: usize, cols: usize) -> bool {
+    let a: Matrix<i64> = Matrix::from_iter(rows, inner, (0..).map(|n| n % 7 - 3));
+    let b: Matrix<i64> = Matrix::from_iter(inner, cols, (0..).map(|n| n % 5 - 2));
+
+    let serial = &a * &b;
+    let product = a * b;
+
+    product == serial
+}
+
+#[test]
+fn mul_below_threshold() {
+    // Exactly the threshold, stays serial
+    let cols = PARALLEL_MUL_THRESHOLD / 64;
+    assert!(product_matches_serial(64, 10, cols));
+}
+
+#[test]
+fn mul_above_threshold() {
+    // 64 more cells than the threshold, computed in parallel
+    let cols = PARALLEL_MUL_THRESHOLD / 64 + 1;
+    assert!(product_matches_serial(64, 10, cols));
+}
+
+#[test]
+fn from_fn_par_matches_serial() {
+    let (rows, cols) = (37, 129);
+    let cell = |row: usize, col: usize| row * cols + col;
+
+    let parallel = Matrix::from_fn_par(rows, cols, cell);
+
+    assert_eq!(parallel, Matrix::from_fn(rows, cols, cell));
+    assert_eq!(parallel, Matrix::from_iter(rows, cols, 0..));
+}